@@ -1,8 +1,9 @@
 use async_trait::async_trait;
-use deltalake::datafusion::arrow::datatypes::{DataType, Field, Schema as ArrowSchema};
+use deltalake::datafusion::arrow::datatypes::{DataType, Field, Schema as ArrowSchema, TimeUnit};
 use deltalake::datafusion::arrow::record_batch::RecordBatch;
 use deltalake::datafusion::common::{Result as DataFusionResult, Statistics};
 
+use deltalake::datafusion::dataframe::DataFrame;
 use deltalake::datafusion::datasource::provider::TableProvider;
 use deltalake::datafusion::error::Result;
 use deltalake::datafusion::execution::context::SessionState;
@@ -10,11 +11,16 @@ use deltalake::datafusion::execution::context::SessionState;
 use deltalake::datafusion::logical_expr::{
     Expr, LogicalPlan, TableProviderFilterPushDown, TableType,
 };
+use deltalake::datafusion::physical_expr::{LexOrdering, PhysicalSortExpr};
+use deltalake::datafusion::physical_plan::expressions::col as physical_col;
+use deltalake::datafusion::physical_plan::projection::ProjectionExec;
+use deltalake::datafusion::physical_plan::sorts::sort::SortExec;
 use deltalake::datafusion::physical_plan::ExecutionPlan;
 use deltalake::datafusion::sql::TableReference;
 use deltalake::kernel::Schema as DeltaSchema;
 use deltalake::operations::create::CreateBuilder;
 use deltalake::operations::delete::{DeleteBuilder, DeleteMetrics};
+use deltalake::operations::merge::{MergeBuilder, MergeMetrics};
 use deltalake::operations::optimize::OptimizeBuilder;
 use deltalake::operations::vacuum::VacuumBuilder;
 use deltalake::table::state::DeltaTableState;
@@ -75,8 +81,33 @@ impl DatafusionTable for PgRelation {
             };
 
             // Note: even if you have an int[][], the attribute-type is INT4ARRAYOID and the base is INT4OID
-            let ArrowDataType(datatype) =
-                PgAttribute(base_oid, PgTypeMod(attribute.type_mod())).try_into()?;
+            //
+            // bytea, uuid, json/jsonb, and time/timetz are handled here rather than falling
+            // through to `PgAttribute`'s TryFrom, since none of them map to the scalar Parquet
+            // primitives the rest of that conversion covers.
+            let datatype = if base_oid == PgOid::from(pg_sys::BYTEAOID) {
+                DataType::Binary
+            } else if base_oid == PgOid::from(pg_sys::UUIDOID) {
+                // Stored as its 16 raw bytes rather than the 36-byte hyphenated text form.
+                DataType::FixedSizeBinary(16)
+            } else if base_oid == PgOid::from(pg_sys::JSONOID)
+                || base_oid == PgOid::from(pg_sys::JSONBOID)
+            {
+                // Delta/Parquet have no native JSON logical type, so json/jsonb round-trip as
+                // validated text: well-formedness is checked on insert, and reads hand back the
+                // canonicalized text representation rather than a parsed value.
+                DataType::Utf8
+            } else if base_oid == PgOid::from(pg_sys::TIMEOID)
+                || base_oid == PgOid::from(pg_sys::TIMETZOID)
+            {
+                // time and timetz both store microseconds-since-midnight; timetz's UTC offset
+                // isn't representable in Arrow's `Time64` and is normalized away on insert.
+                DataType::Time64(TimeUnit::Microsecond)
+            } else {
+                let ArrowDataType(datatype) =
+                    PgAttribute(base_oid, PgTypeMod(attribute.type_mod())).try_into()?;
+                datatype
+            };
             let field = if is_array {
                 Field::new_list(
                     attname,
@@ -185,6 +216,51 @@ impl Tables {
         Ok(delete_builder.await?)
     }
 
+    /// Runs `source` against this table as a Delta merge: rows matching `predicate` are updated
+    /// per `update_assignments`, and unmatched source rows are inserted per `insert_assignments`.
+    /// Either assignment list may be empty to get an update-only or insert-only merge. Only the
+    /// affected data files are rewritten -- the rest of the table is untouched, same as `delete`.
+    pub async fn merge(
+        &mut self,
+        table_path: &Path,
+        source: DataFrame,
+        predicate: Expr,
+        update_assignments: Vec<(String, Expr)>,
+        insert_assignments: Vec<(String, Expr)>,
+    ) -> Result<(DeltaTable, MergeMetrics), ParadeError> {
+        let provider = Self::get_owned(self, table_path).await?;
+        let delta_table = provider.table();
+
+        let mut merge_builder = MergeBuilder::new(
+            delta_table.log_store(),
+            delta_table
+                .state
+                .ok_or(NotFound::Value(type_name::<DeltaTableState>().to_string()))?,
+            predicate,
+            source,
+        );
+
+        if !update_assignments.is_empty() {
+            merge_builder = merge_builder.when_matched_update(|mut update| {
+                for (column, expr) in &update_assignments {
+                    update = update.update(column, expr.clone());
+                }
+                update
+            })?;
+        }
+
+        if !insert_assignments.is_empty() {
+            merge_builder = merge_builder.when_not_matched_insert(|mut insert| {
+                for (column, expr) in &insert_assignments {
+                    insert = insert.set(column, expr.clone());
+                }
+                insert
+            })?;
+        }
+
+        Ok(merge_builder.await?)
+    }
+
     pub fn deregister(&mut self, table_path: &Path) -> Result<(), ParadeError> {
         self.tables.remove(table_path);
         Ok(())
@@ -314,7 +390,62 @@ impl TableProvider for PgTableProvider {
         filters: &[Expr],
         limit: Option<usize>,
     ) -> DataFusionResult<Arc<dyn ExecutionPlan>> {
-        self.table.scan(session, projection, filters, limit).await
+        // Delta's file layout isn't stable across commits: a DELETE rewrites only the files
+        // containing matching rows, so a later scan can see surviving rows in an order that
+        // depends on how those files happen to be listed rather than on insertion order. Request
+        // the reserved tid column alongside whatever the caller projected and sort by it, so row
+        // order stays tied to the table's logical insertion/commit order no matter how files
+        // were added or rewritten underneath.
+        let schema = self.schema();
+        let Ok(tid_index) = schema.index_of(RESERVED_TID_FIELD) else {
+            return self.table.scan(session, projection, filters, limit).await;
+        };
+
+        let (scan_projection, added_tid) = match projection {
+            Some(cols) if !cols.contains(&tid_index) => {
+                let mut extended = cols.clone();
+                extended.push(tid_index);
+                (Some(extended), true)
+            }
+            Some(cols) => (Some(cols.clone()), false),
+            None => (None, false),
+        };
+
+        let plan = self
+            .table
+            .scan(session, scan_projection.as_ref(), filters, limit)
+            .await?;
+
+        let plan_schema = plan.schema();
+        let Ok(sort_index) = plan_schema.index_of(RESERVED_TID_FIELD) else {
+            return Ok(plan);
+        };
+
+        let sorted: Arc<dyn ExecutionPlan> = Arc::new(SortExec::new(
+            LexOrdering::new(vec![PhysicalSortExpr {
+                expr: physical_col(RESERVED_TID_FIELD, &plan_schema)?,
+                options: Default::default(),
+            }]),
+            plan,
+        ));
+
+        if !added_tid {
+            return Ok(sorted);
+        }
+
+        // We only added the tid column to sort by -- project it back out before returning.
+        let sorted_schema = sorted.schema();
+        let output_columns = sorted_schema
+            .fields()
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| *index != sort_index)
+            .map(|(_, field)| -> DataFusionResult<_> {
+                Ok((physical_col(field.name(), &sorted_schema)?, field.name().clone()))
+            })
+            .collect::<DataFusionResult<Vec<_>>>()?;
+
+        Ok(Arc::new(ProjectionExec::try_new(output_columns, sorted)?))
     }
 
     #[allow(deprecated)]
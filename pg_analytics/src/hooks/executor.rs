@@ -5,6 +5,7 @@ use deltalake::datafusion::common::arrow::array::types::UInt64Type;
 use deltalake::datafusion::common::arrow::array::RecordBatch;
 use deltalake::datafusion::error::DataFusionError;
 use deltalake::datafusion::logical_expr::LogicalPlan;
+use deltalake::datafusion::scalar::ScalarValue;
 use deltalake::datafusion::sql::parser;
 use deltalake::datafusion::sql::parser::DFParser;
 use deltalake::datafusion::sql::planner::SqlToRel;
@@ -19,7 +20,11 @@ use crate::datafusion::datatype::{
 };
 use crate::datafusion::table::ParadeTable;
 use crate::errors::ParadeError;
+use crate::hooks::copy;
+use crate::hooks::federation::{self, RtableKind};
 use crate::hooks::handler::DeltaHandler;
+use crate::hooks::merge;
+use crate::hooks::recursive_cte;
 
 pub fn executor_run(
     query_desc: PgBox<pg_sys::QueryDesc>,
@@ -37,12 +42,83 @@ pub fn executor_run(
         let ps = query_desc.plannedstmt;
         let rtable = (*ps).rtable;
 
+        if rtable.is_null() {
+            prev_hook(query_desc, direction, count, execute_once);
+            return Ok(());
+        }
+
+        // A query that mixes a heap relation with a deltalake relation (e.g. a join between the
+        // two) used to be rejected outright. Federate it through DataFusion instead: the heap
+        // side is scanned via SPI and registered as an in-memory table alongside the deltalake
+        // schema provider. Only SELECT is federated today, and a MERGE with a heap source goes to
+        // the MERGE branch below -- any other mixed INSERT/UPDATE/DELETE still falls back to
+        // Postgres's executor, which raises its own "not yet supported".
+        let is_mixed = matches!(federation::classify_rtable(rtable), RtableKind::Mixed);
+        if is_mixed {
+            if query_desc.operation == pg_sys::CmdType_CMD_SELECT {
+                let query = CStr::from_ptr(query_desc.sourceText).to_str()?;
+                let batches = federation::execute_federated_select(rtable, query)?;
+                send_tuples_if_necessary(query_desc.into_pg(), batches)?;
+                return Ok(());
+            }
+            if query_desc.operation != pg_sys::CmdType_CMD_MERGE {
+                prev_hook(query_desc, direction, count, execute_once);
+                return Ok(());
+            }
+        }
+
         // Only use this hook for deltalake tables
-        if rtable.is_null() || !DeltaHandler::rtable_is_delta(rtable)? {
+        if !is_mixed && !DeltaHandler::rtable_is_delta(rtable)? {
             prev_hook(query_desc, direction, count, execute_once);
             return Ok(());
         }
 
+        // MERGE is lowered onto Delta's merge operation rather than DataFusion's own (unsupported)
+        // logical plan for it -- see `merge::plan_merge` for the statement shapes this covers.
+        if query_desc.operation == pg_sys::CmdType_CMD_MERGE {
+            let query = CStr::from_ptr(query_desc.sourceText).to_str()?;
+            let dialect = PostgreSqlDialect {};
+            let ast = DFParser::parse_sql_with_dialect(query, &dialect)
+                .map_err(|err| ParadeError::DataFusion(DataFusionError::SQL(err)))?;
+
+            let Some(upsert) = merge::plan_merge(&ast[0])? else {
+                prev_hook(query_desc, direction, count, execute_once);
+                return Ok(());
+            };
+
+            let elements = (*rtable).elements;
+            let rte = (*elements.offset(0)).ptr_value as *mut pg_sys::RangeTblEntry;
+            let relation = pg_sys::RelationIdGetRelation((*rte).relid);
+            let pg_relation = PgRelation::from_pg_owned(relation);
+            let parade_table = ParadeTable::from_pg(&pg_relation)?;
+            let schema_name = parade_table.schema_name()?;
+
+            // The MERGE source can be an ordinary heap table, which unlike the target isn't
+            // resolvable through the deltalake schema provider -- `register_source_table` scans
+            // and registers it the same way `federation::register_heap_relations` does for a
+            // federated join's probe side (a deltalake source is left as is), then the resulting
+            // DataFrame is handed to `Tables::merge` instead of a bare table name.
+            let source = DatafusionContext::with_session_context(|context| {
+                federation::register_source_table(context, upsert.source_table.as_str())?;
+                Ok(task::block_on(context.table(upsert.source_table.as_str()))?)
+            })?;
+
+            let metrics = DatafusionContext::with_schema_provider(schema_name.as_str(), |provider| {
+                task::block_on(provider.merge(
+                    upsert.target_table.as_str(),
+                    source.clone(),
+                    upsert.predicate.clone(),
+                    upsert.update_assignments.clone(),
+                    upsert.insert_assignments.clone(),
+                ))
+            })?;
+
+            (*(*query_desc.clone().into_pg()).estate).es_processed =
+                (metrics.num_target_rows_updated + metrics.num_target_rows_inserted) as u64;
+
+            return Ok(());
+        }
+
         // Only use this hook for SELECT queries
         // INSERT/UPDATE/DELETE are handled by the table access method
         if query_desc.operation != pg_sys::CmdType_CMD_SELECT
@@ -69,6 +145,7 @@ pub fn executor_run(
             let context_provider = ParadeContextProvider::new()?;
             let sql_to_rel = SqlToRel::new(&context_provider);
             let logical_plan = sql_to_rel.statement_to_plan(statement.clone())?;
+            let logical_plan = bind_params(&query_desc, logical_plan)?;
             info!("converted AST into logical plan");
 
             let elements = (*rtable).elements;
@@ -110,10 +187,22 @@ pub fn executor_run(
         let statement = &ast[0];
         info!("query parsed into AST");
 
+        // A self-referential CTE isn't resolvable by the regular single-shot logical plan below
+        // (the CTE name isn't a real table until a prior iteration's working set exists), so it's
+        // evaluated as its own fixpoint loop instead -- see `recursive_cte::execute`.
+        if let Some(recursive_plan) = recursive_cte::plan_recursive_cte(statement)? {
+            let batches = DatafusionContext::with_session_context(|context| {
+                recursive_cte::execute(context, recursive_plan)
+            })?;
+            send_tuples_if_necessary(query_desc.into_pg(), batches)?;
+            return Ok(());
+        }
+
         // Convert the AST into a logical plan
         let context_provider = ParadeContextProvider::new()?;
         let sql_to_rel = SqlToRel::new(&context_provider);
         let logical_plan = sql_to_rel.statement_to_plan(statement.clone())?;
+        let logical_plan = bind_params(&query_desc, logical_plan)?;
         info!("converted AST into logical plan");
 
         // Execute the logical plan
@@ -130,6 +219,17 @@ pub fn executor_run(
             (*(*query_desc.clone().into_pg()).estate).es_processed = num_updated;
         }
 
+        // A `COPY (...) TO STDOUT WITH (FORMAT PARQUET | ARROW)` wraps this SELECT; stream the
+        // Arrow batches straight into the requested container instead of letting Postgres's
+        // regular dest receiver re-encode them tuple-by-tuple as CSV.
+        if is_select {
+            let format = copy::requested_format(&query_desc)?;
+            if format != copy::CopyFormat::Csv {
+                copy::send_columnar(format, &batches)?;
+                return Ok(());
+            }
+        }
+
         // Return result tuples
         send_tuples_if_necessary(query_desc.into_pg(), batches)?;
 
@@ -137,6 +237,46 @@ pub fn executor_run(
     }
 }
 
+/// Substitutes the bound values for a query's `$1, $2, ...` placeholders into `logical_plan`, so
+/// the same parsed plan works for both a plain statement and a PREPARE/EXECUTE or
+/// extended-query-protocol statement bound to concrete values. A no-op when the query has no
+/// params.
+unsafe fn bind_params(
+    query_desc: &PgBox<pg_sys::QueryDesc>,
+    logical_plan: LogicalPlan,
+) -> Result<LogicalPlan, ParadeError> {
+    match bind_param_values(query_desc)? {
+        Some(param_values) => Ok(logical_plan.with_param_values(param_values)?),
+        None => Ok(logical_plan),
+    }
+}
+
+/// Reads the bound values for a query's `$1, $2, ...` placeholders out of `query_desc.params`
+/// (the `ParamListInfo`), converting each `Datum` to a `ScalarValue` via `PostgresTypeTranslator`
+/// keyed on the param's `ptype` oid. Returns `None` when the query has no params.
+unsafe fn bind_param_values(
+    query_desc: &PgBox<pg_sys::QueryDesc>,
+) -> Result<Option<Vec<ScalarValue>>, ParadeError> {
+    let param_list = query_desc.params;
+    if param_list.is_null() || (*param_list).numParams == 0 {
+        return Ok(None);
+    }
+
+    let params = (*param_list).params.as_mut_ptr();
+    let mut values = Vec::with_capacity((*param_list).numParams as usize);
+    for i in 0..(*param_list).numParams as usize {
+        let param = params.add(i);
+        let oid = PgOid::from((*param).ptype);
+        values.push(PostgresTypeTranslator::to_datafusion_scalar(
+            oid,
+            (*param).value,
+            (*param).isnull,
+        )?);
+    }
+
+    Ok(Some(values))
+}
+
 #[inline]
 unsafe fn send_tuples_if_necessary(
     query_desc: *mut pg_sys::QueryDesc,
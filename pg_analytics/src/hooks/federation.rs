@@ -0,0 +1,355 @@
+use async_std::task;
+use deltalake::datafusion::arrow::array::{
+    Array, ArrayRef, BooleanBuilder, Float32Builder, Float64Builder, Int16Builder, Int32Builder,
+    Int64Builder, RecordBatch, StringBuilder,
+};
+use deltalake::datafusion::arrow::datatypes::{DataType, Field, Schema as ArrowSchema};
+use deltalake::datafusion::logical_expr::{JoinType, LogicalPlan};
+use deltalake::datafusion::prelude::{col, Expr};
+use deltalake::datafusion::sql::parser::DFParser;
+use deltalake::datafusion::sql::planner::SqlToRel;
+use deltalake::datafusion::sql::sqlparser::dialect::PostgreSqlDialect;
+use pgrx::prelude::*;
+use pgrx::spi::Spi;
+use pgrx::PgList;
+use std::collections::HashSet;
+use std::ffi::CStr;
+use std::sync::Arc;
+
+use crate::datafusion::context::{DatafusionContext, ParadeContextProvider};
+use crate::errors::ParadeError;
+
+/// How a query's range table relates to deltalake: whether it touches no deltalake relations,
+/// only deltalake relations, or a mix of deltalake and ordinary heap relations. [`executor_run`]
+/// uses this to decide whether to hand the query back to Postgres's executor, run it entirely
+/// through the existing all-deltalake path, or federate it across both engines.
+pub enum RtableKind {
+    NoDelta,
+    AllDelta,
+    Mixed,
+}
+
+/// Classifies every plain-relation entry of `rtable` by whether its table access method is
+/// `deltalake`, without erroring on a mix -- unlike `DeltaHandler::rtable_is_delta`, which exists
+/// to reject queries the all-deltalake path can't handle.
+pub unsafe fn classify_rtable(rtable: *mut pg_sys::List) -> RtableKind {
+    let list = PgList::<pg_sys::RangeTblEntry>::from_pg(rtable);
+    let (mut saw_delta, mut saw_heap) = (false, false);
+
+    for rte in list.iter_ptr() {
+        if (*rte).rtekind != pg_sys::RTEKind_RTE_RELATION {
+            continue;
+        }
+        match relation_is_delta((*rte).relid) {
+            true => saw_delta = true,
+            false => saw_heap = true,
+        }
+    }
+
+    match (saw_delta, saw_heap) {
+        (false, _) => RtableKind::NoDelta,
+        (true, false) => RtableKind::AllDelta,
+        (true, true) => RtableKind::Mixed,
+    }
+}
+
+unsafe fn relation_is_delta(relid: pg_sys::Oid) -> bool {
+    let relation = pg_sys::RelationIdGetRelation(relid);
+    if relation.is_null() {
+        return false;
+    }
+    let am_oid = (*(*relation).rd_rel).relam;
+    let am_name = pg_sys::get_am_name(am_oid);
+    let is_delta = !am_name.is_null() && CStr::from_ptr(am_name).to_str() == Ok("deltalake");
+    pg_sys::RelationClose(relation);
+    is_delta
+}
+
+/// Like [`relation_is_delta`], but looked up by name (as named in a `LogicalPlan`'s join keys)
+/// rather than an already-resolved `Oid`. A name that doesn't resolve to a relation is treated as
+/// not-delta, so callers fall back to the safe default instead of erroring.
+fn relation_name_is_delta(name: &str) -> bool {
+    match unsafe { PgRelation::open_with_name(name) } {
+        Ok(relation) => unsafe { relation_is_delta(relation.oid()) },
+        Err(_) => false,
+    }
+}
+
+/// Up to this many rows, a heap relation feeding a federated join is scanned and registered into
+/// DataFusion as an in-memory probe table; above it, federation bails out with an honest error
+/// rather than silently materializing an unbounded heap table into memory.
+const MAX_PROBE_ROWS: i64 = 100_000;
+
+/// Runs a query whose range table mixes deltalake and heap relations: each heap relation is
+/// scanned in full via SPI into an Arrow [`RecordBatch`] and registered into the DataFusion
+/// session as an in-memory table, so the existing deltalake schema provider and this session's
+/// heap tables can be resolved side by side by the same logical plan.
+///
+/// When the resulting plan is a single inner or semi join between one heap (probe) table and one
+/// deltalake (build) table, the heap side's distinct join-key values are pushed down as an
+/// `IN (...)` filter on the deltalake scan -- an index-style semi-join -- instead of materializing
+/// the full deltalake relation into a hash join, which is the default DataFusion would otherwise
+/// pick for any non-trivial equijoin.
+pub unsafe fn execute_federated_select(
+    rtable: *mut pg_sys::List,
+    query: &str,
+) -> Result<Vec<RecordBatch>, ParadeError> {
+    let dialect = PostgreSqlDialect {};
+    let ast = DFParser::parse_sql_with_dialect(query, &dialect)
+        .map_err(|err| ParadeError::DataFusion(deltalake::datafusion::error::DataFusionError::SQL(err)))?;
+    let statement = &ast[0];
+
+    let context_provider = ParadeContextProvider::new()?;
+    let sql_to_rel = SqlToRel::new(&context_provider);
+    let logical_plan = sql_to_rel.statement_to_plan(statement.clone())?;
+
+    DatafusionContext::with_session_context(|context| {
+        register_heap_relations(context, rtable)?;
+
+        if let Some(batches) = task::block_on(try_probed_join(context, &logical_plan))? {
+            return Ok(batches);
+        }
+
+        let dataframe = task::block_on(context.execute_logical_plan(logical_plan.clone()))?;
+        Ok(task::block_on(dataframe.collect())?)
+    })
+}
+
+unsafe fn register_heap_relations(
+    context: &deltalake::datafusion::execution::context::SessionContext,
+    rtable: *mut pg_sys::List,
+) -> Result<(), ParadeError> {
+    let list = PgList::<pg_sys::RangeTblEntry>::from_pg(rtable);
+
+    for rte in list.iter_ptr() {
+        if (*rte).rtekind != pg_sys::RTEKind_RTE_RELATION || relation_is_delta((*rte).relid) {
+            continue;
+        }
+
+        let relation = PgRelation::open((*rte).relid);
+        let relation_name = relation.name().to_string();
+        let batch = scan_heap_relation(&relation_name)?;
+        context.register_batch(&relation_name, batch)?;
+    }
+
+    Ok(())
+}
+
+/// Registers `table_name` into `context` as an in-memory batch if it names an ordinary heap
+/// relation, the same way [`register_heap_relations`] does for a federated join's probe side --
+/// a deltalake table is already reachable through the session's schema provider and needs no
+/// such registration. Used to turn a MERGE's `USING <source>` clause into a `DataFrame`
+/// [`crate::datafusion::table::Tables::merge`] can run against.
+pub(crate) unsafe fn register_source_table(
+    context: &deltalake::datafusion::execution::context::SessionContext,
+    table_name: &str,
+) -> Result<(), ParadeError> {
+    if relation_name_is_delta(table_name) {
+        return Ok(());
+    }
+
+    let batch = scan_heap_relation(table_name)?;
+    context.register_batch(table_name, batch)?;
+
+    Ok(())
+}
+
+/// Scans `relation_name` in full via SPI and materializes it as a single Arrow [`RecordBatch`].
+/// Errors out past [`MAX_PROBE_ROWS`] rather than buffering an unbounded heap table into memory.
+fn scan_heap_relation(relation_name: &str) -> Result<RecordBatch, ParadeError> {
+    Spi::connect(|client| {
+        let count_query = format!("SELECT COUNT(*) FROM {relation_name}");
+        let row_count: i64 = client
+            .select(&count_query, None, &[])?
+            .first()
+            .get_one()?
+            .unwrap_or(0);
+
+        if row_count > MAX_PROBE_ROWS {
+            error!(
+                "federated join: heap table {relation_name} has {row_count} rows, which exceeds \
+                 the {MAX_PROBE_ROWS}-row limit for the probe side of a heap/deltalake join"
+            );
+        }
+
+        let select_query = format!("SELECT * FROM {relation_name}");
+        let tuptable = client.select(&select_query, None, &[])?;
+        let columns: Vec<(String, pg_sys::Oid)> = tuptable
+            .columns()
+            .map(|col| (col.name().to_string(), col.type_oid()))
+            .collect();
+
+        let mut builders: Vec<ColumnBuilder> = columns
+            .iter()
+            .map(|(_, type_oid)| ColumnBuilder::new(*type_oid))
+            .collect::<Result<_, _>>()?;
+
+        for row in tuptable.into_iter() {
+            for (index, builder) in builders.iter_mut().enumerate() {
+                builder.append_from_row(&row, index + 1)?;
+            }
+        }
+
+        let fields: Vec<Field> = columns
+            .iter()
+            .zip(builders.iter())
+            .map(|((name, _), builder)| Field::new(name, builder.data_type(), true))
+            .collect();
+        let arrow_schema = Arc::new(ArrowSchema::new(fields));
+        let arrays: Vec<ArrayRef> = builders.into_iter().map(|builder| builder.finish()).collect();
+
+        Ok(RecordBatch::try_new(arrow_schema, arrays)?)
+    })
+}
+
+/// A column-at-a-time Arrow builder for the scalar types a heap table commonly federates with.
+/// Anything outside this list (arrays, the richer deltalake-only types added in earlier
+/// requests, …) isn't supported on the heap side of a federated join yet.
+enum ColumnBuilder {
+    Int16(Int16Builder),
+    Int32(Int32Builder),
+    Int64(Int64Builder),
+    Float32(Float32Builder),
+    Float64(Float64Builder),
+    Boolean(BooleanBuilder),
+    Utf8(StringBuilder),
+}
+
+impl ColumnBuilder {
+    fn new(type_oid: pg_sys::Oid) -> Result<Self, ParadeError> {
+        Ok(match type_oid {
+            pg_sys::INT2OID => Self::Int16(Int16Builder::new()),
+            pg_sys::INT4OID => Self::Int32(Int32Builder::new()),
+            pg_sys::INT8OID => Self::Int64(Int64Builder::new()),
+            pg_sys::FLOAT4OID => Self::Float32(Float32Builder::new()),
+            pg_sys::FLOAT8OID => Self::Float64(Float64Builder::new()),
+            pg_sys::BOOLOID => Self::Boolean(BooleanBuilder::new()),
+            pg_sys::TEXTOID | pg_sys::VARCHAROID | pg_sys::BPCHAROID => {
+                Self::Utf8(StringBuilder::new())
+            }
+            other => error!("federated join: heap column type {other:?} is not supported"),
+        })
+    }
+
+    fn data_type(&self) -> DataType {
+        match self {
+            Self::Int16(_) => DataType::Int16,
+            Self::Int32(_) => DataType::Int32,
+            Self::Int64(_) => DataType::Int64,
+            Self::Float32(_) => DataType::Float32,
+            Self::Float64(_) => DataType::Float64,
+            Self::Boolean(_) => DataType::Boolean,
+            Self::Utf8(_) => DataType::Utf8,
+        }
+    }
+
+    fn append_from_row(
+        &mut self,
+        row: &pgrx::spi::SpiHeapTupleData,
+        ordinal: usize,
+    ) -> Result<(), ParadeError> {
+        match self {
+            Self::Int16(builder) => builder.append_option(row.get::<i16>(ordinal)?),
+            Self::Int32(builder) => builder.append_option(row.get::<i32>(ordinal)?),
+            Self::Int64(builder) => builder.append_option(row.get::<i64>(ordinal)?),
+            Self::Float32(builder) => builder.append_option(row.get::<f32>(ordinal)?),
+            Self::Float64(builder) => builder.append_option(row.get::<f64>(ordinal)?),
+            Self::Boolean(builder) => builder.append_option(row.get::<bool>(ordinal)?),
+            Self::Utf8(builder) => builder.append_option(row.get::<String>(ordinal)?),
+        }
+        Ok(())
+    }
+
+    fn finish(self) -> ArrayRef {
+        match self {
+            Self::Int16(mut builder) => Arc::new(builder.finish()),
+            Self::Int32(mut builder) => Arc::new(builder.finish()),
+            Self::Int64(mut builder) => Arc::new(builder.finish()),
+            Self::Float32(mut builder) => Arc::new(builder.finish()),
+            Self::Float64(mut builder) => Arc::new(builder.finish()),
+            Self::Boolean(mut builder) => Arc::new(builder.finish()),
+            Self::Utf8(mut builder) => Arc::new(builder.finish()),
+        }
+    }
+}
+
+/// If `plan` is exactly an inner or left-semi equijoin between one heap table (already registered
+/// via [`register_heap_relations`]) and one deltalake table, executes the heap side, collects its
+/// distinct join-key values, and re-runs the join with those values pushed down as an `IN (...)`
+/// filter on the deltalake side. Returns `Ok(None)` for any other plan shape, leaving the caller
+/// to fall back to the regular (hash) join.
+async fn try_probed_join(
+    context: &deltalake::datafusion::execution::context::SessionContext,
+    plan: &LogicalPlan,
+) -> Result<Option<Vec<RecordBatch>>, ParadeError> {
+    let LogicalPlan::Join(join) = plan else {
+        return Ok(None);
+    };
+    if !matches!(join.join_type, JoinType::Inner | JoinType::LeftSemi) {
+        return Ok(None);
+    }
+    let [(left_key, right_key)] = join.on.as_slice() else {
+        return Ok(None); // only a single equi-join key is pushed down for now
+    };
+    let (Some(probe_key), Some(build_key)) = (left_key.try_as_col(), right_key.try_as_col())
+    else {
+        return Ok(None);
+    };
+
+    // `join.on` pairs are (left-side expr, right-side expr), so `probe_key`/`build_key` name
+    // columns of `join.left`/`join.right` respectively. Only the heap-probes-delta shape is
+    // supported here: bail out to the regular hash join for anything else (delta-probes-heap,
+    // delta-delta, heap-heap), rather than assuming the left side is always heap.
+    let (Some(probe_relation), Some(build_relation)) =
+        (probe_key.relation.as_ref(), build_key.relation.as_ref())
+    else {
+        return Ok(None);
+    };
+    if relation_name_is_delta(probe_relation.table()) || !relation_name_is_delta(build_relation.table())
+    {
+        return Ok(None);
+    }
+
+    // The heap side is always the probe side: it's the side this module just materialized in
+    // full via SPI, so its row count is already bounded by `MAX_PROBE_ROWS`.
+    let probe_table = context.table(&probe_key.relation.clone().unwrap_or_default()).await;
+    let Ok(probe_table) = probe_table else {
+        return Ok(None);
+    };
+
+    let probe_batches = probe_table
+        .clone()
+        .select_columns(&[&probe_key.name])?
+        .distinct()?
+        .collect()
+        .await?;
+
+    let mut probe_keys = HashSet::new();
+    for batch in &probe_batches {
+        let column = batch.column(0);
+        for row in 0..column.len() {
+            if !column.is_null(row) {
+                probe_keys.insert(deltalake::datafusion::common::ScalarValue::try_from_array(
+                    column, row,
+                )?);
+            }
+        }
+    }
+
+    let in_list: Vec<Expr> = probe_keys.into_iter().map(Expr::Literal).collect();
+    if in_list.is_empty() {
+        return Ok(Some(Vec::new())); // no probe keys -- the join can't match anything
+    }
+
+    let filtered_plan = deltalake::datafusion::logical_expr::LogicalPlanBuilder::from((*join.right).clone())
+        .filter(col(build_key.name.as_str()).in_list(in_list, false))?
+        .build()?;
+
+    let rewritten = LogicalPlan::Join(deltalake::datafusion::logical_expr::Join {
+        right: Arc::new(filtered_plan),
+        ..join.clone()
+    });
+
+    let dataframe = context.execute_logical_plan(rewritten).await?;
+    Ok(Some(dataframe.collect().await?))
+}
@@ -0,0 +1,156 @@
+use deltalake::datafusion::logical_expr::{Expr, LogicalPlanBuilder};
+use deltalake::datafusion::sql::planner::{PlannerContext, SqlToRel};
+use deltalake::datafusion::sql::sqlparser::ast::{
+    Assignment, AssignmentTarget, MergeAction, MergeClauseKind, MergeInsertKind, Statement,
+    TableFactor,
+};
+use deltalake::datafusion::sql::TableReference;
+use pgrx::prelude::*;
+
+use crate::datafusion::context::ParadeContextProvider;
+use crate::errors::ParadeError;
+
+/// A `MERGE INTO target USING source ON <predicate> WHEN MATCHED THEN UPDATE ... WHEN NOT
+/// MATCHED THEN INSERT ...` statement lowered onto Delta's merge operation. Only the simplest
+/// shape is recognized -- one unconditional `WHEN MATCHED` update clause and one unconditional
+/// `WHEN NOT MATCHED` insert clause -- and any other clause shape is rejected with an error.
+pub struct UpsertPlan {
+    pub target_table: String,
+    pub source_table: String,
+    pub predicate: Expr,
+    pub update_assignments: Vec<(String, Expr)>,
+    pub insert_assignments: Vec<(String, Expr)>,
+}
+
+/// Parses `statement` as a `MERGE` and plans its predicate and assignments against the combined
+/// schema of its target and source tables. Returns `Ok(None)` for any other statement, leaving
+/// the caller to handle it through the regular INSERT/UPDATE/DELETE path.
+pub fn plan_merge(statement: &Statement) -> Result<Option<UpsertPlan>, ParadeError> {
+    let Statement::Merge {
+        table,
+        source,
+        on,
+        clauses,
+        ..
+    } = statement
+    else {
+        return Ok(None);
+    };
+
+    let target_table = table_name(table);
+    let source_table = table_name(source);
+
+    let context_provider = ParadeContextProvider::new()?;
+    let sql_to_rel = SqlToRel::new(&context_provider);
+
+    let target_source =
+        context_provider.get_table_source(TableReference::bare(target_table.clone()))?;
+    let source_source =
+        context_provider.get_table_source(TableReference::bare(source_table.clone()))?;
+    let combined_plan = LogicalPlanBuilder::scan(target_table.clone(), target_source, None)?
+        .cross_join(LogicalPlanBuilder::scan(source_table.clone(), source_source, None)?.build()?)?
+        .build()?;
+    let schema = combined_plan.schema();
+
+    let mut planner_context = PlannerContext::new();
+    let predicate = sql_to_rel.sql_to_expr((**on).clone(), schema, &mut planner_context)?;
+
+    let mut update_assignments = None;
+    let mut insert_assignments = None;
+
+    for clause in clauses {
+        if clause.predicate.is_some() {
+            error!("MERGE clauses with an extra AND predicate are not yet supported");
+        }
+        match (&clause.clause_kind, &clause.action) {
+            (MergeClauseKind::Matched, MergeAction::Update { assignments })
+                if update_assignments.is_none() =>
+            {
+                update_assignments = Some(plan_assignments(
+                    &sql_to_rel,
+                    schema,
+                    &mut planner_context,
+                    assignments,
+                )?);
+            }
+            (MergeClauseKind::NotMatched, MergeAction::Insert(insert))
+                if insert_assignments.is_none() =>
+            {
+                insert_assignments = Some(plan_insert(
+                    &sql_to_rel,
+                    schema,
+                    &mut planner_context,
+                    insert,
+                )?);
+            }
+            _ => error!(
+                "only a single WHEN MATCHED UPDATE and a single WHEN NOT MATCHED INSERT clause \
+                 are supported per MERGE"
+            ),
+        }
+    }
+
+    Ok(Some(UpsertPlan {
+        target_table,
+        source_table,
+        predicate,
+        update_assignments: update_assignments.unwrap_or_default(),
+        insert_assignments: insert_assignments.unwrap_or_default(),
+    }))
+}
+
+fn table_name(factor: &TableFactor) -> String {
+    match factor {
+        TableFactor::Table { name, .. } => name.to_string(),
+        _ => error!("MERGE only supports plain table references for its target and source"),
+    }
+}
+
+fn plan_insert(
+    sql_to_rel: &SqlToRel<ParadeContextProvider>,
+    schema: &deltalake::datafusion::common::DFSchema,
+    planner_context: &mut PlannerContext,
+    insert: &deltalake::datafusion::sql::sqlparser::ast::MergeInsertExpr,
+) -> Result<Vec<(String, Expr)>, ParadeError> {
+    let MergeInsertKind::Values(values) = &insert.kind else {
+        error!("MERGE INSERT only supports an explicit VALUES row");
+    };
+    let [row] = values.rows.as_slice() else {
+        error!("MERGE INSERT only supports a single VALUES row");
+    };
+    if insert.columns.len() != row.len() {
+        error!("MERGE INSERT column list must match the VALUES row length");
+    }
+
+    insert
+        .columns
+        .iter()
+        .zip(row)
+        .map(|(column, value)| {
+            let expr = sql_to_rel.sql_to_expr(value.clone(), schema, planner_context)?;
+            Ok((column.value.clone(), expr))
+        })
+        .collect()
+}
+
+fn plan_assignments(
+    sql_to_rel: &SqlToRel<ParadeContextProvider>,
+    schema: &deltalake::datafusion::common::DFSchema,
+    planner_context: &mut PlannerContext,
+    assignments: &[Assignment],
+) -> Result<Vec<(String, Expr)>, ParadeError> {
+    assignments
+        .iter()
+        .map(|assignment| {
+            let AssignmentTarget::ColumnName(name) = &assignment.target else {
+                error!("MERGE UPDATE only supports assigning to a plain column");
+            };
+            let column = match name.0.last() {
+                Some(ident) => ident.value.clone(),
+                None => error!("MERGE UPDATE assignment target must name a column"),
+            };
+            let expr = sql_to_rel.sql_to_expr(assignment.value.clone(), schema, planner_context)?;
+            Ok((column, expr))
+        })
+        .collect()
+}
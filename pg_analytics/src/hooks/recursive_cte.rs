@@ -0,0 +1,167 @@
+use async_std::task;
+use deltalake::datafusion::arrow::array::ArrayRef;
+use deltalake::datafusion::arrow::compute::filter_record_batch;
+use deltalake::datafusion::arrow::record_batch::RecordBatch;
+use deltalake::datafusion::common::ScalarValue;
+use deltalake::datafusion::execution::context::SessionContext;
+use deltalake::datafusion::sql::sqlparser::ast::{SetExpr, SetOperator, SetQuantifier, Statement};
+use pgrx::prelude::*;
+use std::collections::HashSet;
+
+use crate::errors::ParadeError;
+
+/// A `WITH RECURSIVE` query is re-evaluated until its working set stops growing; this caps how
+/// many times the recursive term can run so a self-referential query that never reaches a
+/// fixpoint (e.g. a cyclic hierarchy with no loop guard of its own) fails loudly instead of
+/// hanging the backend.
+const MAX_ITERATIONS: usize = 1_000;
+
+/// A `WITH RECURSIVE cte_name AS (anchor UNION [ALL] recursive_term) SELECT ... FROM cte_name`
+/// query, lowered onto a fixpoint loop over the session context rather than planned in one shot
+/// -- the self-reference inside `recursive_term` doesn't resolve to a real table until the
+/// previous iteration's working set has been registered under `cte_name`.
+pub struct RecursiveCtePlan {
+    cte_name: String,
+    distinct: bool,
+    anchor_sql: String,
+    recursive_sql: String,
+    outer_sql: String,
+}
+
+/// Parses `statement` for a single `WITH RECURSIVE` CTE whose body is `anchor UNION [ALL]
+/// recursive_term`. Returns `Ok(None)` for a statement without `WITH RECURSIVE`, leaving the
+/// caller to plan it the regular way; a `WITH RECURSIVE` of any other shape is rejected with an
+/// error.
+pub fn plan_recursive_cte(statement: &Statement) -> Result<Option<RecursiveCtePlan>, ParadeError> {
+    let Statement::Query(query) = statement else {
+        return Ok(None);
+    };
+    let Some(with) = &query.with else {
+        return Ok(None);
+    };
+    if !with.recursive {
+        return Ok(None);
+    }
+    let [cte] = with.cte_tables.as_slice() else {
+        error!("WITH RECURSIVE only supports a single self-referential CTE");
+    };
+
+    let SetExpr::SetOperation {
+        op,
+        set_quantifier,
+        left,
+        right,
+    } = cte.query.body.as_ref()
+    else {
+        error!("a recursive CTE's body must be `anchor UNION [ALL] recursive_term`");
+    };
+    if !matches!(op, SetOperator::Union) {
+        error!("a recursive CTE's body must be a UNION, not {op}");
+    }
+
+    let mut outer_query = query.clone();
+    outer_query.with = None;
+
+    Ok(Some(RecursiveCtePlan {
+        cte_name: cte.alias.name.value.clone(),
+        distinct: !matches!(set_quantifier, SetQuantifier::All),
+        anchor_sql: left.to_string(),
+        recursive_sql: right.to_string(),
+        outer_sql: outer_query.to_string(),
+    }))
+}
+
+/// Evaluates `plan` against `context` by materializing the anchor term, then repeatedly running
+/// the recursive term against the current working set and unioning in only the rows it hasn't
+/// already produced, until a pass adds nothing new (or [`MAX_ITERATIONS`] is hit).
+pub fn execute(context: &SessionContext, plan: RecursiveCtePlan) -> Result<Vec<RecordBatch>, ParadeError> {
+    let anchor_batches = task::block_on(async {
+        context.sql(&plan.anchor_sql).await?.collect().await
+    })?;
+
+    let mut seen = HashSet::new();
+    if plan.distinct {
+        for batch in &anchor_batches {
+            for row in 0..batch.num_rows() {
+                seen.insert(row_key(batch, row)?);
+            }
+        }
+    }
+
+    let mut all_batches = anchor_batches.clone();
+    let mut working_set = anchor_batches;
+    let mut iterations = 0;
+
+    while working_set.iter().any(|batch| batch.num_rows() > 0) {
+        iterations += 1;
+        if iterations > MAX_ITERATIONS {
+            error!(
+                "WITH RECURSIVE did not reach a fixpoint within {MAX_ITERATIONS} iterations"
+            );
+        }
+
+        context.deregister_table(plan.cte_name.as_str())?;
+        register_batches(context, plan.cte_name.as_str(), &working_set)?;
+
+        let next_batches = task::block_on(async {
+            context.sql(&plan.recursive_sql).await?.collect().await
+        })?;
+
+        working_set = if plan.distinct {
+            next_batches
+                .iter()
+                .map(|batch| dedupe_new_rows(batch, &mut seen))
+                .collect::<Result<Vec<_>, ParadeError>>()?
+        } else {
+            next_batches
+        };
+
+        all_batches.extend(working_set.iter().cloned());
+    }
+
+    context.deregister_table(plan.cte_name.as_str())?;
+    register_batches(context, plan.cte_name.as_str(), &all_batches)?;
+    let result = task::block_on(async { context.sql(&plan.outer_sql).await?.collect().await })?;
+    context.deregister_table(plan.cte_name.as_str())?;
+
+    Ok(result)
+}
+
+fn register_batches(
+    context: &SessionContext,
+    name: &str,
+    batches: &[RecordBatch],
+) -> Result<(), ParadeError> {
+    let Some(schema) = batches.first().map(|batch| batch.schema()) else {
+        return Ok(());
+    };
+    let merged = deltalake::datafusion::arrow::compute::concat_batches(&schema, batches)?;
+    context.register_batch(name, merged)?;
+    Ok(())
+}
+
+/// A hashable snapshot of one row, used to tell whether the recursive term has already produced
+/// it in an earlier iteration (standard `UNION`, as opposed to `UNION ALL`, semantics).
+fn row_key(batch: &RecordBatch, row: usize) -> Result<Vec<ScalarValue>, ParadeError> {
+    (0..batch.num_columns())
+        .map(|col| Ok(ScalarValue::try_from_array(batch.column(col), row)?))
+        .collect()
+}
+
+fn dedupe_new_rows(
+    batch: &RecordBatch,
+    seen: &mut HashSet<Vec<ScalarValue>>,
+) -> Result<RecordBatch, ParadeError> {
+    let mut keep = Vec::with_capacity(batch.num_rows());
+    for row in 0..batch.num_rows() {
+        let key = row_key(batch, row)?;
+        keep.push(seen.insert(key));
+    }
+    let mask: ArrayRef = std::sync::Arc::new(deltalake::datafusion::arrow::array::BooleanArray::from(keep));
+    Ok(filter_record_batch(
+        batch,
+        mask.as_any()
+            .downcast_ref::<deltalake::datafusion::arrow::array::BooleanArray>()
+            .expect("mask is always a BooleanArray"),
+    )?)
+}
@@ -1,8 +1,12 @@
 use pgrx::{pg_sys::ItemPointerData, *};
 use rustc_hash::{FxHashMap, FxHashSet};
+use tantivy::tokenizer::TokenStream;
 
+use crate::index_access::options::ParadeOptions;
 use crate::manager::get_executor_manager;
+use crate::metrics::WRITER_METRICS;
 use crate::operator::scan_index;
+use crate::parade_index::fields::ParadeTokenizer;
 
 #[pg_extern]
 pub fn rank_bm25(ctid: Option<ItemPointerData>) -> f32 {
@@ -51,3 +55,60 @@ pub fn l2_normalized_bm25(
         None => 0.0,
     }
 }
+
+/// Runs `input` through the tokenizer configured on `index_name` and returns one row per
+/// resulting token, so users can see exactly how their text will be analyzed before indexing
+/// it. Useful for debugging parameterized tokenizers (ngram, regex) where the effect of a
+/// configuration isn't obvious from the option string alone.
+#[pg_extern]
+pub fn tokenize(
+    index_name: &str,
+    input: &str,
+) -> TableIterator<
+    'static,
+    (
+        name!(token, String),
+        name!(position, i32),
+        name!(start, i32),
+        name!(end, i32),
+    ),
+> {
+    let indexrel = PgRelation::open_with_name_and_share_lock(index_name)
+        .unwrap_or_else(|err| panic!("could not open index {index_name}: {err}"));
+
+    let tokenizer_value = unsafe { &*(indexrel.rd_options as *mut ParadeOptions) }.get_tokenizer();
+
+    let json_value = if tokenizer_value.trim_start().starts_with('{') {
+        serde_json::from_str::<serde_json::Value>(&tokenizer_value)
+            .unwrap_or_else(|err| panic!("invalid tokenizer {tokenizer_value}: {err}"))
+    } else {
+        serde_json::Value::String(tokenizer_value.clone())
+    };
+    let tokenizer: ParadeTokenizer = serde_json::from_value(json_value)
+        .unwrap_or_else(|err| panic!("invalid tokenizer {tokenizer_value}: {err}"));
+
+    let manager = crate::parade_index::tokenizer::setup_tokenizers(std::iter::once(&tokenizer));
+    let mut analyzer = manager
+        .get(&tokenizer.name())
+        .unwrap_or_else(|| panic!("tokenizer {} was not registered", tokenizer.name()));
+    let mut token_stream = analyzer.token_stream(input);
+
+    let mut rows = Vec::new();
+    while let Some(token) = token_stream.next() {
+        rows.push((
+            token.text.clone(),
+            token.position as i32,
+            token.offset_from as i32,
+            token.offset_to as i32,
+        ));
+    }
+
+    TableIterator::new(rows)
+}
+
+/// Renders insert throughput and latency for the `pg_bm25` insert worker in the Prometheus text
+/// exposition format, so operators can scrape it through SQL.
+#[pg_extern]
+pub fn writer_metrics() -> String {
+    WRITER_METRICS.render()
+}
@@ -0,0 +1,38 @@
+use pgrx::{GucContext, GucFlags, GucRegistry, GucSetting};
+
+static WRITER_MAX_RETRIES: GucSetting<i32> = GucSetting::<i32>::new(5);
+static WRITER_RETRY_BASE_DELAY_MS: GucSetting<i32> = GucSetting::<i32>::new(100);
+
+pub fn init() {
+    GucRegistry::define_int_guc(
+        "paradedb.writer_max_retries",
+        "Maximum number of attempts ParadeWriterClient makes to reach the insert worker",
+        "A request only retries while the insert worker looks like it's still starting up \
+         (connection refused or timed out); anything else fails immediately.",
+        &WRITER_MAX_RETRIES,
+        1,
+        100,
+        GucContext::Userset,
+        GucFlags::default(),
+    );
+
+    GucRegistry::define_int_guc(
+        "paradedb.writer_retry_base_delay_ms",
+        "Initial backoff delay, in milliseconds, between writer request retries",
+        "Doubles on each retry, so the wait after failed attempt N is roughly \
+         writer_retry_base_delay_ms * 2^(N-1).",
+        &WRITER_RETRY_BASE_DELAY_MS,
+        1,
+        60_000,
+        GucContext::Userset,
+        GucFlags::default(),
+    );
+}
+
+pub fn writer_max_retries() -> &'static GucSetting<i32> {
+    &WRITER_MAX_RETRIES
+}
+
+pub fn writer_retry_base_delay_ms() -> &'static GucSetting<i32> {
+    &WRITER_RETRY_BASE_DELAY_MS
+}
@@ -2,6 +2,8 @@ use pgrx::pg_sys::AsPgCStr;
 use pgrx::*;
 use std::ffi::CStr;
 
+use crate::parade_index::fields::ParadeTokenizer;
+
 /* ADDING OPTIONS (modeled after ZomboDB)
  * in init(), call pg_sys::add_{type}_reloption (check postgres docs for what args you need)
  * add the corresponding entries to ParadeOptions struct definition
@@ -41,12 +43,39 @@ extern "C" fn validate_tokenizer(value: *const std::os::raw::c_char) {
 
     info!("tokenizer: {}", value);
 
-    // TODO: not hardcode this
-    if value != "default" && value != "raw" && value != "en_stem" {
-        panic!("invalid tokenizer: {}", value);
+    // A tokenizer is either one of the built-in names ("default", "raw", "en_stem",
+    // "whitespace") or a JSON object describing a parameterized tokenizer, e.g.
+    // {"type":"ngram","min_gram":3,"max_gram":5,"prefix_only":false}. Bare names are treated
+    // as a JSON string so both forms go through the same `ParadeTokenizer` deserializer, which
+    // also catches bad parameters (an invalid regex, or min_gram > max_gram) at CREATE INDEX
+    // time rather than at query time.
+    let json_value = if value.trim_start().starts_with('{') {
+        match serde_json::from_str::<serde_json::Value>(value) {
+            Ok(json_value) => json_value,
+            Err(err) => report_invalid_tokenizer(value, err),
+        }
+    } else {
+        serde_json::Value::String(value.to_string())
+    };
+
+    if let Err(err) = serde_json::from_value::<ParadeTokenizer>(json_value) {
+        report_invalid_tokenizer(value, err);
     }
 }
-// For now, we support changing the tokenizer between default, raw, and en_stem
+
+/// Reports a malformed tokenizer option under the stable `tokenizer_invalid` error code, so
+/// drivers can distinguish this class of CREATE INDEX-time user error from an internal failure
+/// without parsing the message text. `-> !` lets call sites use it in value position.
+fn report_invalid_tokenizer(value: &str, err: impl std::fmt::Display) -> ! {
+    pg_sys::panic::ErrorReport::new(
+        PgSqlErrorCode::ERRCODE_INVALID_PARAMETER_VALUE,
+        format!("[tokenizer_invalid] invalid tokenizer {value}: {err}"),
+        "",
+    )
+    .report(PgLogLevel::PG_ERROR)
+}
+// We support the built-in default/raw/en_stem/whitespace tokenizers, plus parameterized
+// ngram and regex tokenizers (see `ParadeTokenizer`).
 const NUM_REL_OPTS: usize = 1;
 #[pg_guard]
 pub unsafe extern "C" fn amoptions(
@@ -0,0 +1,71 @@
+use once_cell::sync::Lazy;
+use prometheus::{
+    register_histogram_vec, register_int_counter, Encoder, HistogramVec, IntCounter, Registry,
+    TextEncoder,
+};
+
+/// Metrics registry for `pg_bm25`'s writer subsystem, following Garage's `metrics.rs` pattern:
+/// counters and histograms are registered once into a process-wide [`Registry`] and rendered on
+/// demand in the Prometheus text exposition format, rather than pushed anywhere.
+pub static WRITER_METRICS: Lazy<WriterMetrics> = Lazy::new(WriterMetrics::register);
+
+pub struct WriterMetrics {
+    registry: Registry,
+    pub insert_requests_total: IntCounter,
+    pub insert_bytes_total: IntCounter,
+    pub request_duration_seconds: HistogramVec,
+}
+
+impl WriterMetrics {
+    fn register() -> Self {
+        let registry = Registry::new();
+
+        let insert_requests_total = register_int_counter!(
+            "writer_insert_requests_total",
+            "Total number of insert requests sent to the pg_bm25 insert worker"
+        )
+        .expect("writer_insert_requests_total should register");
+        registry
+            .register(Box::new(insert_requests_total.clone()))
+            .expect("writer_insert_requests_total should register with the registry");
+
+        let insert_bytes_total = register_int_counter!(
+            "writer_insert_bytes_total",
+            "Total number of body bytes sent to the pg_bm25 insert worker"
+        )
+        .expect("writer_insert_bytes_total should register");
+        registry
+            .register(Box::new(insert_bytes_total.clone()))
+            .expect("writer_insert_bytes_total should register with the registry");
+
+        let request_duration_seconds = register_histogram_vec!(
+            "writer_request_duration_seconds",
+            "Latency of a request to the pg_bm25 insert worker, from first attempt through the \
+             final response or error",
+            &["request_type", "outcome"]
+        )
+        .expect("writer_request_duration_seconds should register");
+        registry
+            .register(Box::new(request_duration_seconds.clone()))
+            .expect("writer_request_duration_seconds should register with the registry");
+
+        Self {
+            registry,
+            insert_requests_total,
+            insert_bytes_total,
+            request_duration_seconds,
+        }
+    }
+
+    /// Renders every metric in this registry in the Prometheus text exposition format, for the
+    /// `writer_metrics()` SQL function to return as-is.
+    pub fn render(&self) -> String {
+        let metric_families = self.registry.gather();
+        let mut buffer = Vec::new();
+        TextEncoder::new()
+            .encode(&metric_families, &mut buffer)
+            .expect("prometheus metrics should encode to text");
+
+        String::from_utf8(buffer).expect("prometheus text encoding should be valid utf8")
+    }
+}
@@ -1,28 +1,172 @@
+use serde::de::{self, Deserializer};
 use serde::*;
 use tantivy::schema::*;
 
+use super::language::ParadeLanguage;
+
+/// Default minimum detection confidence for the `"multilang"`/`"auto"` tokenizer. Below this,
+/// `whatlang` is considered too unsure to trust and we fall back to the configured
+/// `fallback_language`'s stemmer.
+fn default_confidence_threshold() -> f32 {
+    0.5
+}
+
 // Tokenizers
-// TODO: Custom tokenizers like CJK and ngrams
-#[derive(Default, Copy, Clone, Deserialize, Debug, PartialEq, Eq)]
+//
+// The built-in variants (`default`, `raw`, `en_stem`, `whitespace`) are configured with a bare
+// string, e.g. `tokenizer => 'en_stem'`. The parameterized variants (`ngram`, `regex`,
+// `multilang`/`auto`) are configured with a small JSON object instead, e.g.
+// `tokenizer => '{"type":"ngram","min_gram":3,"max_gram":5,"prefix_only":false}'`, following the
+// same approach Quickwit uses for its custom tokenizers.
+#[derive(Clone, Debug, PartialEq)]
 pub enum ParadeTokenizer {
-    #[serde(rename = "default")]
-    #[default]
     Default,
-    #[serde(rename = "raw")]
     Raw,
-    #[serde(rename = "en_stem")]
     EnStem,
-    #[serde(rename = "whitespace")]
     WhiteSpace,
+    Ngram {
+        min_gram: usize,
+        max_gram: usize,
+        prefix_only: bool,
+    },
+    Regex {
+        pattern: String,
+    },
+    /// Detects each document's language at tokenize time and stems it accordingly, instead of
+    /// forcing a single `en_stem` on a mixed-language corpus. Falls back to `fallback_language`'s
+    /// stemmer when detection confidence is below `threshold` or the detected language has no
+    /// supported stemmer.
+    MultiLang {
+        fallback_language: ParadeLanguage,
+        threshold: f32,
+    },
+}
+
+impl Default for ParadeTokenizer {
+    fn default() -> Self {
+        Self::Default
+    }
 }
 
 impl ParadeTokenizer {
-    pub fn name(&self) -> &str {
+    /// Returns the name under which this tokenizer is registered with the `TokenizerManager`.
+    /// Parameterized tokenizers are given a name that encodes their configuration, since
+    /// tantivy's `TokenizerManager` looks tokenizers up by name rather than by value.
+    pub fn name(&self) -> String {
         match self {
-            ParadeTokenizer::Default => "default",
-            ParadeTokenizer::Raw => "raw",
-            ParadeTokenizer::EnStem => "en_stem",
-            ParadeTokenizer::WhiteSpace => "whitespace",
+            ParadeTokenizer::Default => "default".to_string(),
+            ParadeTokenizer::Raw => "raw".to_string(),
+            ParadeTokenizer::EnStem => "en_stem".to_string(),
+            ParadeTokenizer::WhiteSpace => "whitespace".to_string(),
+            ParadeTokenizer::Ngram {
+                min_gram,
+                max_gram,
+                prefix_only,
+            } => format!("ngram_min{min_gram}_max{max_gram}_prefix{prefix_only}"),
+            ParadeTokenizer::Regex { pattern } => {
+                format!("regex_{:x}", md5_hash(pattern.as_bytes()))
+            }
+            ParadeTokenizer::MultiLang {
+                fallback_language,
+                threshold,
+            } => format!(
+                "multilang_fallback{}_threshold{}",
+                fallback_language.name(),
+                (threshold * 100.0).round() as i32
+            ),
+        }
+    }
+}
+
+/// A tiny, dependency-free hash used only to keep generated regex tokenizer names short and
+/// stable. It does not need to be cryptographically sound, just collision-resistant enough to
+/// distinguish different patterns registered on the same index.
+fn md5_hash(bytes: &[u8]) -> u64 {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = rustc_hash::FxHasher::default();
+    bytes.hash(&mut hasher);
+    hasher.finish()
+}
+
+impl<'de> Deserialize<'de> for ParadeTokenizer {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(tag = "type", rename_all = "snake_case")]
+        enum Parameterized {
+            Ngram {
+                min_gram: usize,
+                max_gram: usize,
+                #[serde(default)]
+                prefix_only: bool,
+            },
+            Regex {
+                pattern: String,
+            },
+            #[serde(alias = "auto")]
+            Multilang {
+                #[serde(default)]
+                fallback_language: ParadeLanguage,
+                #[serde(default = "default_confidence_threshold")]
+                threshold: f32,
+            },
+        }
+
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value {
+            serde_json::Value::String(name) => match name.as_str() {
+                "default" => Ok(ParadeTokenizer::Default),
+                "raw" => Ok(ParadeTokenizer::Raw),
+                "en_stem" => Ok(ParadeTokenizer::EnStem),
+                "whitespace" => Ok(ParadeTokenizer::WhiteSpace),
+                other => Err(de::Error::custom(format!("unknown tokenizer: {other}"))),
+            },
+            object @ serde_json::Value::Object(_) => {
+                let parameterized: Parameterized =
+                    serde_json::from_value(object).map_err(de::Error::custom)?;
+                match parameterized {
+                    Parameterized::Ngram {
+                        min_gram,
+                        max_gram,
+                        prefix_only,
+                    } => {
+                        if min_gram == 0 || min_gram > max_gram {
+                            return Err(de::Error::custom(format!(
+                                "invalid ngram tokenizer: min_gram ({min_gram}) must be > 0 and <= max_gram ({max_gram})"
+                            )));
+                        }
+                        Ok(ParadeTokenizer::Ngram {
+                            min_gram,
+                            max_gram,
+                            prefix_only,
+                        })
+                    }
+                    Parameterized::Regex { pattern } => {
+                        regex::Regex::new(&pattern)
+                            .map_err(|e| de::Error::custom(format!("invalid regex tokenizer pattern {pattern:?}: {e}")))?;
+                        Ok(ParadeTokenizer::Regex { pattern })
+                    }
+                    Parameterized::Multilang {
+                        fallback_language,
+                        threshold,
+                    } => {
+                        if !(0.0..=1.0).contains(&threshold) {
+                            return Err(de::Error::custom(format!(
+                                "invalid multilang tokenizer: threshold ({threshold}) must be between 0.0 and 1.0"
+                            )));
+                        }
+                        Ok(ParadeTokenizer::MultiLang {
+                            fallback_language,
+                            threshold,
+                        })
+                    }
+                }
+            }
+            _ => Err(de::Error::custom(
+                "tokenizer must be either a string (e.g. \"en_stem\") or an object with a \"type\" field (e.g. {\"type\":\"ngram\",...})",
+            )),
         }
     }
 }
@@ -59,7 +203,7 @@ pub enum IndexRecordOptionSchema {
 }
 
 // Text options
-#[derive(Copy, Clone, Debug, Deserialize, utoipa::ToSchema)]
+#[derive(Clone, Debug, Deserialize, utoipa::ToSchema)]
 pub struct ParadeTextOptions {
     #[serde(default)]
     indexed: bool,
@@ -106,7 +250,7 @@ impl From<ParadeTextOptions> for TextOptions {
             let text_field_indexing = TextFieldIndexing::default()
                 .set_index_option(parade_options.record)
                 .set_fieldnorms(parade_options.fieldnorms)
-                .set_tokenizer(parade_options.tokenizer.name());
+                .set_tokenizer(&parade_options.tokenizer.name());
 
             text_options = text_options.set_indexing_options(text_field_indexing);
         }
@@ -200,7 +344,7 @@ impl From<ParadeBooleanOptions> for NumericOptions {
 }
 
 // Json options
-#[derive(Copy, Clone, Debug, Deserialize, utoipa::ToSchema)]
+#[derive(Clone, Debug, Deserialize, utoipa::ToSchema)]
 pub struct ParadeJsonOptions {
     #[serde(default)]
     indexed: bool,
@@ -249,7 +393,7 @@ impl From<ParadeJsonOptions> for JsonObjectOptions {
         if parade_options.indexed {
             let text_field_indexing = TextFieldIndexing::default()
                 .set_index_option(parade_options.record)
-                .set_tokenizer(parade_options.tokenizer.name());
+                .set_tokenizer(&parade_options.tokenizer.name());
 
             json_options = json_options.set_indexing_options(text_field_indexing);
         }
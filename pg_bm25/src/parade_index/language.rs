@@ -0,0 +1,108 @@
+use serde::{Deserialize, Serialize};
+
+/// Languages we can stem for, i.e. the intersection of what `tantivy::tokenizer::Stemmer`
+/// supports and what `whatlang` can reliably detect. Used by the `"multilang"`/`"auto"`
+/// tokenizer to pick a per-document stemmer instead of forcing a single `en_stem` on
+/// mixed-language corpora.
+#[derive(Copy, Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum ParadeLanguage {
+    #[default]
+    English,
+    French,
+    German,
+    Spanish,
+    Russian,
+    Italian,
+    Portuguese,
+    Dutch,
+    Danish,
+    Swedish,
+    Norwegian,
+    Finnish,
+}
+
+impl ParadeLanguage {
+    pub fn all() -> [ParadeLanguage; 12] {
+        [
+            ParadeLanguage::English,
+            ParadeLanguage::French,
+            ParadeLanguage::German,
+            ParadeLanguage::Spanish,
+            ParadeLanguage::Russian,
+            ParadeLanguage::Italian,
+            ParadeLanguage::Portuguese,
+            ParadeLanguage::Dutch,
+            ParadeLanguage::Danish,
+            ParadeLanguage::Swedish,
+            ParadeLanguage::Norwegian,
+            ParadeLanguage::Finnish,
+        ]
+    }
+
+    pub fn stemmer_language(&self) -> tantivy::tokenizer::Language {
+        match self {
+            ParadeLanguage::English => tantivy::tokenizer::Language::English,
+            ParadeLanguage::French => tantivy::tokenizer::Language::French,
+            ParadeLanguage::German => tantivy::tokenizer::Language::German,
+            ParadeLanguage::Spanish => tantivy::tokenizer::Language::Spanish,
+            ParadeLanguage::Russian => tantivy::tokenizer::Language::Russian,
+            ParadeLanguage::Italian => tantivy::tokenizer::Language::Italian,
+            ParadeLanguage::Portuguese => tantivy::tokenizer::Language::Portuguese,
+            ParadeLanguage::Dutch => tantivy::tokenizer::Language::Dutch,
+            ParadeLanguage::Danish => tantivy::tokenizer::Language::Danish,
+            ParadeLanguage::Swedish => tantivy::tokenizer::Language::Swedish,
+            ParadeLanguage::Norwegian => tantivy::tokenizer::Language::Norwegian,
+            ParadeLanguage::Finnish => tantivy::tokenizer::Language::Finnish,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ParadeLanguage::English => "english",
+            ParadeLanguage::French => "french",
+            ParadeLanguage::German => "german",
+            ParadeLanguage::Spanish => "spanish",
+            ParadeLanguage::Russian => "russian",
+            ParadeLanguage::Italian => "italian",
+            ParadeLanguage::Portuguese => "portuguese",
+            ParadeLanguage::Dutch => "dutch",
+            ParadeLanguage::Danish => "danish",
+            ParadeLanguage::Swedish => "swedish",
+            ParadeLanguage::Norwegian => "norwegian",
+            ParadeLanguage::Finnish => "finnish",
+        }
+    }
+
+    /// Maps a `whatlang` detection result onto one of our supported stemmer languages, if any.
+    /// Languages `whatlang` can detect but we have no stemmer for (e.g. Japanese, Arabic)
+    /// return `None`, which the caller treats the same as "low confidence": fall back.
+    fn from_whatlang(lang: whatlang::Lang) -> Option<Self> {
+        match lang {
+            whatlang::Lang::Eng => Some(ParadeLanguage::English),
+            whatlang::Lang::Fra => Some(ParadeLanguage::French),
+            whatlang::Lang::Deu => Some(ParadeLanguage::German),
+            whatlang::Lang::Spa => Some(ParadeLanguage::Spanish),
+            whatlang::Lang::Rus => Some(ParadeLanguage::Russian),
+            whatlang::Lang::Ita => Some(ParadeLanguage::Italian),
+            whatlang::Lang::Por => Some(ParadeLanguage::Portuguese),
+            whatlang::Lang::Nld => Some(ParadeLanguage::Dutch),
+            whatlang::Lang::Dan => Some(ParadeLanguage::Danish),
+            whatlang::Lang::Swe => Some(ParadeLanguage::Swedish),
+            whatlang::Lang::Nob => Some(ParadeLanguage::Norwegian),
+            whatlang::Lang::Fin => Some(ParadeLanguage::Finnish),
+            _ => None,
+        }
+    }
+}
+
+/// Detects the language of `text` and, if confident enough, returns a supported stemmer
+/// language for it. Returns `None` when `whatlang` can't make a confident call or detects a
+/// language we don't carry a stemmer for, in which case the caller should fall back.
+pub fn detect_language(text: &str, confidence_threshold: f32) -> Option<ParadeLanguage> {
+    let info = whatlang::detect(text)?;
+    if info.confidence() < confidence_threshold as f64 {
+        return None;
+    }
+    ParadeLanguage::from_whatlang(info.lang())
+}
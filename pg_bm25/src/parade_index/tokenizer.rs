@@ -0,0 +1,159 @@
+use rustc_hash::FxHashMap;
+use tantivy::tokenizer::{
+    BoxTokenStream, LowerCaser, NgramTokenizer, RawTokenizer, RemoveLongFilter, SimpleTokenizer,
+    Stemmer, TextAnalyzer, Token, TokenStream, Tokenizer, TokenizerManager, WhitespaceTokenizer,
+};
+
+use super::fields::ParadeTokenizer;
+use super::language::{detect_language, ParadeLanguage};
+
+/// A tantivy [`Tokenizer`] that splits (or matches) tokens using a compiled regular expression,
+/// mirroring Quickwit's regex tokenizer. Every non-overlapping match of `pattern` against the
+/// input text becomes one token, positioned by match order and carrying its byte offsets.
+#[derive(Clone)]
+pub struct RegexTokenizer {
+    regex: regex::Regex,
+}
+
+impl RegexTokenizer {
+    pub fn new(pattern: &str) -> Result<Self, regex::Error> {
+        Ok(Self {
+            regex: regex::Regex::new(pattern)?,
+        })
+    }
+}
+
+pub struct RegexTokenStream<'a> {
+    text: &'a str,
+    matches: regex::Matches<'a, 'a>,
+    token: Token,
+}
+
+impl<'a> TokenStream for RegexTokenStream<'a> {
+    fn advance(&mut self) -> bool {
+        match self.matches.next() {
+            Some(m) => {
+                self.token.position = self.token.position.wrapping_add(1);
+                self.token.offset_from = m.start();
+                self.token.offset_to = m.end();
+                self.token.text.clear();
+                self.token.text.push_str(&self.text[m.start()..m.end()]);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn token(&self) -> &Token {
+        &self.token
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        &mut self.token
+    }
+}
+
+impl Tokenizer for RegexTokenizer {
+    type TokenStream<'a> = RegexTokenStream<'a>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        RegexTokenStream {
+            text,
+            matches: self.regex.find_iter(text),
+            token: Token::default(),
+        }
+    }
+}
+
+/// Builds the lowercase+simple+stemmer chain for a single language, shared by the plain
+/// `en_stem` tokenizer and by each per-language branch of the `multilang` tokenizer.
+fn stemmed_analyzer(language: ParadeLanguage) -> TextAnalyzer {
+    TextAnalyzer::builder(SimpleTokenizer::default())
+        .filter(RemoveLongFilter::limit(40))
+        .filter(LowerCaser)
+        .filter(Stemmer::new(language.stemmer_language()))
+        .build()
+}
+
+/// A tantivy [`Tokenizer`] that detects each document's language at tokenize time and routes it
+/// to the matching stemmer, instead of forcing a single stemmer on a mixed-language corpus.
+#[derive(Clone)]
+pub struct MultiLangTokenizer {
+    threshold: f32,
+    fallback: TextAnalyzer,
+    stemmers: FxHashMap<ParadeLanguage, TextAnalyzer>,
+}
+
+impl MultiLangTokenizer {
+    pub fn new(fallback_language: ParadeLanguage, threshold: f32) -> Self {
+        Self {
+            threshold,
+            fallback: stemmed_analyzer(fallback_language),
+            stemmers: ParadeLanguage::all()
+                .into_iter()
+                .map(|language| (language, stemmed_analyzer(language)))
+                .collect(),
+        }
+    }
+}
+
+impl Tokenizer for MultiLangTokenizer {
+    type TokenStream<'a> = BoxTokenStream<'a>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        let detected = detect_language(text, self.threshold);
+        match detected.and_then(|language| self.stemmers.get_mut(&language)) {
+            Some(analyzer) => analyzer.token_stream(text),
+            None => self.fallback.token_stream(text),
+        }
+    }
+}
+
+impl ParadeTokenizer {
+    /// Builds the tantivy [`TextAnalyzer`] this tokenizer configuration describes.
+    pub fn build_analyzer(&self) -> TextAnalyzer {
+        match self {
+            ParadeTokenizer::Default => TextAnalyzer::builder(SimpleTokenizer::default())
+                .filter(RemoveLongFilter::limit(40))
+                .filter(LowerCaser)
+                .build(),
+            ParadeTokenizer::Raw => TextAnalyzer::builder(RawTokenizer::default()).build(),
+            ParadeTokenizer::EnStem => stemmed_analyzer(ParadeLanguage::English),
+            ParadeTokenizer::WhiteSpace => {
+                TextAnalyzer::builder(WhitespaceTokenizer::default()).build()
+            }
+            ParadeTokenizer::Ngram {
+                min_gram,
+                max_gram,
+                prefix_only,
+            } => TextAnalyzer::builder(
+                NgramTokenizer::new(*min_gram, *max_gram, *prefix_only)
+                    .expect("min_gram/max_gram were already validated on deserialize"),
+            )
+            .build(),
+            ParadeTokenizer::Regex { pattern } => TextAnalyzer::builder(
+                RegexTokenizer::new(pattern).expect("pattern was already validated on deserialize"),
+            )
+            .build(),
+            ParadeTokenizer::MultiLang {
+                fallback_language,
+                threshold,
+            } => TextAnalyzer::builder(MultiLangTokenizer::new(*fallback_language, *threshold))
+                .build(),
+        }
+    }
+}
+
+/// Builds a tantivy [`TokenizerManager`] with one entry per tokenizer in `tokenizers`, each
+/// registered under [`ParadeTokenizer::name`] -- the name `TextFieldIndexing::set_tokenizer` is
+/// given when a field's `ParadeTextOptions`/`ParadeJsonOptions` is turned into tantivy
+/// `TextOptions`/`JsonObjectOptions`. Parameterized tokenizers (ngram, regex, multilang) need this
+/// registration step because, unlike the built-ins, tantivy has no static entry for them: without
+/// it, opening an index with one of these configured on a field panics with "unknown tokenizer".
+pub fn setup_tokenizers<'a>(tokenizers: impl IntoIterator<Item = &'a ParadeTokenizer>) -> TokenizerManager {
+    let manager = TokenizerManager::new();
+    for tokenizer in tokenizers {
+        manager.register(&tokenizer.name(), tokenizer.build_analyzer());
+    }
+    manager
+}
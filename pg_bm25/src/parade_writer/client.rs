@@ -1,10 +1,24 @@
 use crate::WriterInitError;
 use crate::{
     json::builder::JsonBuilder,
+    metrics::WRITER_METRICS,
     parade_writer::{ParadeWriterRequest, ParadeWriterResponse},
 };
+use once_cell::sync::Lazy;
 use pgrx::{log, PGRXSharedMemory};
-use std::{error::Error, net::SocketAddr};
+use std::net::SocketAddr;
+use std::time::{Duration, Instant};
+use thiserror::Error;
+
+/// Shared, keep-alive-pooled client used for every request to the insert worker. Built once per
+/// backend (like [`crate::index::search::SEARCH_EXECUTOR`]) instead of per-call, since a fresh
+/// `reqwest::blocking::Client` opens and tears down its own connection pool on every `insert`.
+static WRITER_CLIENT: Lazy<reqwest::blocking::Client> = Lazy::new(|| {
+    reqwest::blocking::Client::builder()
+        .pool_idle_timeout(Duration::from_secs(90))
+        .build()
+        .expect("pg_bm25 writer HTTP client should build")
+});
 
 #[derive(Copy, Clone, Default)]
 pub struct ParadeWriterClient {
@@ -12,6 +26,25 @@ pub struct ParadeWriterClient {
     error: Option<WriterInitError>,
 }
 
+#[derive(Error, Debug)]
+pub enum WriterClientError {
+    #[error("pg_bm25 writer not yet initialized, but received request: {0:?}")]
+    NotInitialized(ParadeWriterRequest),
+
+    #[error("pg_bm25 writer request failed after {attempts} attempt(s): {source}")]
+    Request {
+        attempts: u32,
+        #[source]
+        source: reqwest::Error,
+    },
+
+    #[error("failed to decode pg_bm25 writer response: {0}")]
+    Decode(Box<dyn std::error::Error + Send + Sync>),
+
+    #[error("unexpected response from pg_bm25 writer while inserting: {0:?}")]
+    UnexpectedResponse(ParadeWriterResponse),
+}
+
 impl ParadeWriterClient {
     pub fn set_addr(&mut self, addr: SocketAddr) {
         self.addr = Some(addr);
@@ -24,7 +57,7 @@ impl ParadeWriterClient {
     fn send_request(
         &self,
         request: ParadeWriterRequest,
-    ) -> Result<ParadeWriterResponse, Box<dyn Error>> {
+    ) -> Result<ParadeWriterResponse, WriterClientError> {
         let addr = match self.addr {
             // If there's no addr, the server hasn't started yet.
             // We won't send the shutdown request,but it is up to the insert worker
@@ -36,21 +69,85 @@ impl ParadeWriterClient {
                 }
                 // If it wasn't a shutdown request, then we have a problem if the server has not
                 // been started. Return an error.
-                req => {
-                    return Err(format!(
-                        "pg_bm25 writer not yet initialized, but received request: {req:?}"
-                    )
-                    .into())
-                }
+                req => return Err(WriterClientError::NotInitialized(req)),
             },
             Some(addr) => addr,
         };
 
+        let request_type = Self::request_type_label(&request);
         let bytes: Vec<u8> = request.into();
-        let client = reqwest::blocking::Client::new();
-        let response = client.post(&format!("http://{addr}")).body(bytes).send()?;
-        let response_body = response.bytes()?;
-        ParadeWriterResponse::try_from(response_body.to_vec().as_slice()).map_err(|e| e.into())
+
+        if request_type == "insert" {
+            WRITER_METRICS.insert_requests_total.inc();
+            WRITER_METRICS
+                .insert_bytes_total
+                .inc_by(bytes.len() as u64);
+        }
+
+        let start = Instant::now();
+        let result = Self::send_with_retry(addr, bytes);
+        let outcome = if result.is_ok() { "success" } else { "error" };
+        WRITER_METRICS
+            .request_duration_seconds
+            .with_label_values(&[request_type, outcome])
+            .observe(start.elapsed().as_secs_f64());
+
+        let response_body = result?;
+        ParadeWriterResponse::try_from(response_body.as_slice()).map_err(WriterClientError::Decode)
+    }
+
+    /// Label for the `request_type` dimension of `writer_request_duration_seconds`, matched on
+    /// up front so the retry loop in [`Self::send_with_retry`] doesn't need to know about it.
+    fn request_type_label(request: &ParadeWriterRequest) -> &'static str {
+        match request {
+            ParadeWriterRequest::Insert(..) => "insert",
+            ParadeWriterRequest::Shutdown => "shutdown",
+        }
+    }
+
+    /// Sends `bytes` to the insert worker at `addr`, retrying with exponential backoff -- modeled
+    /// on delta-rs's `object_store` retry client -- while the failure looks like the worker is
+    /// still coming up (connection refused or timed out) rather than a request the worker itself
+    /// rejected.
+    fn send_with_retry(addr: SocketAddr, bytes: Vec<u8>) -> Result<Vec<u8>, WriterClientError> {
+        let max_attempts = (crate::gucs::writer_max_retries().get() as u32).max(1);
+        let mut delay = Duration::from_millis(crate::gucs::writer_retry_base_delay_ms().get() as u64);
+        let mut attempt = 0;
+
+        loop {
+            attempt += 1;
+
+            let result = WRITER_CLIENT
+                .post(format!("http://{addr}"))
+                .body(bytes.clone())
+                .send()
+                .and_then(|response| response.bytes());
+
+            match result {
+                Ok(response_body) => return Ok(response_body.to_vec()),
+                Err(err) if attempt < max_attempts && Self::is_retryable(&err) => {
+                    log!(
+                        "pg_bm25 writer request failed ({err}), retrying in {delay:?} \
+                         (attempt {attempt}/{max_attempts})"
+                    );
+                    std::thread::sleep(delay);
+                    delay *= 2;
+                }
+                Err(source) => {
+                    return Err(WriterClientError::Request {
+                        attempts: attempt,
+                        source,
+                    })
+                }
+            }
+        }
+    }
+
+    /// A connection refused or timed out most often means the insert worker hasn't finished
+    /// starting up yet, so it's worth retrying; anything else (a malformed request, a worker-side
+    /// panic response) won't be fixed by trying again.
+    fn is_retryable(err: &reqwest::Error) -> bool {
+        err.is_connect() || err.is_timeout()
     }
 
     fn get_data_directory(name: &str) -> String {
@@ -72,21 +169,21 @@ impl ParadeWriterClient {
         }
     }
 
-    pub fn insert(&self, index_name: &str, json_builder: JsonBuilder) {
-        let response = self
-            .send_request(ParadeWriterRequest::Insert(
-                Self::get_data_directory(&index_name),
-                json_builder,
-            ))
-            .expect("error while sending insert request}");
-
-        match response {
-            ParadeWriterResponse::Ok => {}
-            error => panic!("unexpected error while inserting: {error:?}"),
-        };
+    pub fn insert(
+        &self,
+        index_name: &str,
+        json_builder: JsonBuilder,
+    ) -> Result<(), WriterClientError> {
+        match self.send_request(ParadeWriterRequest::Insert(
+            Self::get_data_directory(index_name),
+            json_builder,
+        ))? {
+            ParadeWriterResponse::Ok => Ok(()),
+            other => Err(WriterClientError::UnexpectedResponse(other)),
+        }
     }
 
-    pub fn shutdown(&self) -> Result<(), Box<dyn Error>> {
+    pub fn shutdown(&self) -> Result<(), WriterClientError> {
         self.send_request(ParadeWriterRequest::Shutdown)?;
         Ok(())
     }
@@ -1,9 +1,12 @@
+use arrow::array::RecordBatch;
+use arrow::ipc::reader::StreamReader;
+use arrow::ipc::writer::StreamWriter;
 use interprocess::os::unix::fifo_file;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use serde_json::{Deserializer, StreamDeserializer};
 use std::fs::File;
-use std::io::{BufReader, Write};
+use std::io::{BufRead, BufReader, Write};
 use std::marker::PhantomData;
 use std::os::unix::prelude::PermissionsExt;
 use std::path::{Path, PathBuf};
@@ -14,29 +17,74 @@ pub enum WriterTransferMessage<T> {
     Done,
 }
 
-pub struct WriterTransferMessageIterator<'a, T> {
-    stream:
-        StreamDeserializer<'a, serde_json::de::IoRead<BufReader<File>>, WriterTransferMessage<T>>,
+/// How [`WriterTransferProducer`]/[`WriterTransferConsumer`] frame a stream of
+/// [`WriterTransferMessage`]s onto the FIFO. [`JsonFraming`] (the default) round-trips each
+/// message through `serde_json`, and works for any `Serialize + DeserializeOwned` payload.
+/// [`ArrowIpcFraming`] is specialized for `RecordBatch` payloads: it writes the Arrow IPC
+/// streaming format instead, which preserves wide numeric/decimal/timestamp types exactly and
+/// lets the DuckDB/DataFusion side ingest the batches zero-copy.
+pub trait TransferFraming<T>: Default {
+    type Reader: Iterator<Item = T>;
+
+    fn write_data(&mut self, pipe: &mut File, data: &T) -> std::io::Result<()>;
+    fn write_done(&mut self, pipe: &mut File) -> std::io::Result<()>;
+    fn open_reader(pipe: BufReader<File>) -> Self::Reader;
 }
 
-impl<'a, T> Iterator for WriterTransferMessageIterator<'a, T>
+/// The original framing: each message is a standalone `serde_json` value, read back with a
+/// [`StreamDeserializer`]. Works for any `Serialize + DeserializeOwned` payload, at the cost of
+/// routing every value through generic serde.
+#[derive(Default)]
+pub struct JsonFraming;
+
+impl<T> TransferFraming<T> for JsonFraming
 where
-    T: DeserializeOwned + 'a,
+    T: Serialize + DeserializeOwned,
 {
-    type Item = serde_json::Result<WriterTransferMessage<T>>;
+    type Reader = JsonFramingReader<T>;
+
+    fn write_data(&mut self, pipe: &mut File, data: &T) -> std::io::Result<()> {
+        pgrx::log!("WRITING MESSAGE!");
+        let message = WriterTransferMessage::Data(data);
+        let serialized = serde_json::to_vec(&message)?;
+        pipe.write_all(&serialized)?;
+        pipe.flush()
+    }
+
+    fn write_done(&mut self, pipe: &mut File) -> std::io::Result<()> {
+        pgrx::log!("WRITING DONE MESSAGE!");
+        let message: WriterTransferMessage<T> = WriterTransferMessage::Done;
+        let serialized = serde_json::to_vec(&message)?;
+        pipe.write_all(&serialized)?;
+        pipe.flush()
+    }
+
+    fn open_reader(pipe: BufReader<File>) -> Self::Reader {
+        let stream =
+            Deserializer::from_reader(pipe).into_iter::<WriterTransferMessage<T>>();
+        JsonFramingReader { stream }
+    }
+}
+
+pub struct JsonFramingReader<T> {
+    stream: StreamDeserializer<'static, serde_json::de::IoRead<BufReader<File>>, WriterTransferMessage<T>>,
+}
+
+impl<T: DeserializeOwned> Iterator for JsonFramingReader<T> {
+    type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
         match self.stream.next() {
-            Some(Ok(WriterTransferMessage::Data(builder))) => {
+            Some(Ok(WriterTransferMessage::Data(data))) => {
                 pgrx::log!("GOT MESSAGE");
-                Some(Ok(WriterTransferMessage::Data(builder)))
+                Some(data)
             }
             Some(Ok(WriterTransferMessage::Done)) => {
                 pgrx::log!("GOT DONE MESSAGE");
-                None // End interator
+                None // End iterator
             }
-            Some(Err(e)) => {
-                pgrx::log!("Error parsing JSON in writer transfer consumer message: {e:?}",);
+            Some(Err(err)) => {
+                pgrx::log!("Error parsing JSON in writer transfer consumer message: {err:?}");
                 None // End iterator on error
             }
             None => None, // No more items
@@ -44,37 +92,119 @@ where
     }
 }
 
-pub struct WriterTransferProducer<T: Serialize> {
+/// Frames [`RecordBatch`] payloads as the Arrow IPC streaming format: an IPC schema message is
+/// written once, on the first call to `write_data`, followed by one IPC `RecordBatch` message per
+/// [`WriterTransferMessage::Data`], and a final IPC end-of-stream marker in place of `Done`.
+pub struct ArrowIpcFraming {
+    writer: Option<StreamWriter<File>>,
+}
+
+impl Default for ArrowIpcFraming {
+    fn default() -> Self {
+        Self { writer: None }
+    }
+}
+
+impl TransferFraming<RecordBatch> for ArrowIpcFraming {
+    type Reader = ArrowIpcFramingReader;
+
+    fn write_data(&mut self, pipe: &mut File, data: &RecordBatch) -> std::io::Result<()> {
+        if self.writer.is_none() {
+            let pipe_handle = pipe.try_clone()?;
+            let writer = StreamWriter::try_new(pipe_handle, data.schema().as_ref())
+                .map_err(|err| std::io::Error::new(std::io::ErrorKind::Other, err))?;
+            self.writer = Some(writer);
+        }
+
+        let writer = self
+            .writer
+            .as_mut()
+            .expect("Arrow IPC writer was just initialized");
+        writer
+            .write(data)
+            .map_err(|err| std::io::Error::new(std::io::ErrorKind::Other, err))
+    }
+
+    fn write_done(&mut self, _pipe: &mut File) -> std::io::Result<()> {
+        if let Some(mut writer) = self.writer.take() {
+            writer
+                .finish()
+                .map_err(|err| std::io::Error::new(std::io::ErrorKind::Other, err))?;
+        }
+        Ok(())
+    }
+
+    fn open_reader(mut pipe: BufReader<File>) -> Self::Reader {
+        // When zero `RecordBatch`es were ever written, `write_data`'s writer never initialized,
+        // so no IPC schema message was sent and the pipe is immediately at EOF. That's a
+        // legitimate empty-result transfer, not a corrupt stream -- treat it as zero rows instead
+        // of letting `StreamReader::try_new` fail trying to read a schema message that was never
+        // sent.
+        let is_empty = pipe.fill_buf().map(|buf| buf.is_empty()).unwrap_or(true);
+        if is_empty {
+            return ArrowIpcFramingReader { reader: None };
+        }
+
+        let reader = StreamReader::try_new(pipe, None).unwrap_or_else(|err| {
+            panic!("could not open Arrow IPC stream from writer transfer pipe: {err}")
+        });
+        ArrowIpcFramingReader {
+            reader: Some(reader),
+        }
+    }
+}
+
+pub struct ArrowIpcFramingReader {
+    reader: Option<StreamReader<BufReader<File>>>,
+}
+
+impl Iterator for ArrowIpcFramingReader {
+    type Item = RecordBatch;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.reader.as_mut()?.next() {
+            Some(Ok(batch)) => Some(batch),
+            Some(Err(err)) => {
+                pgrx::log!("error reading Arrow IPC record batch from writer transfer pipe: {err}");
+                None
+            }
+            None => None,
+        }
+    }
+}
+
+/// The zero-copy alternative to `WriterTransferProducer<RecordBatch>` (which defaults to
+/// [`JsonFraming`]): frames batches as Arrow IPC instead of round-tripping each one through serde.
+pub type RecordBatchTransferProducer = WriterTransferProducer<RecordBatch, ArrowIpcFraming>;
+
+/// The [`ArrowIpcFraming`]-paired consumer for [`RecordBatchTransferProducer`].
+pub type RecordBatchTransferConsumer = WriterTransferConsumer<ArrowIpcFraming>;
+
+pub struct WriterTransferProducer<T, F: TransferFraming<T> = JsonFraming> {
     pipe: File,
     pipe_path: PathBuf,
+    framing: F,
     marker: PhantomData<T>,
 }
 
-impl<T: Serialize> WriterTransferProducer<T> {
+impl<T, F: TransferFraming<T>> WriterTransferProducer<T, F> {
     pub fn new() -> std::io::Result<Self> {
         let pipe_path = crate::env::paradedb_transfer_pipe_path();
         let pipe = Self::create_named_pipe_file(&pipe_path)?;
         Ok(Self {
             pipe,
             pipe_path,
+            framing: F::default(),
             marker: PhantomData,
         })
     }
 
     pub fn write_message(&mut self, data: &T) -> std::io::Result<()> {
-        pgrx::log!("WRITING MESSAGE!");
-        let message = WriterTransferMessage::Data(data);
-        let serialized = serde_json::to_vec(&message)?;
-        self.write_all(&serialized)?;
-        self.flush()
+        self.framing.write_data(&mut self.pipe, data)
     }
 
     pub fn write_done_message(&mut self) -> std::io::Result<()> {
-        pgrx::log!("WRITING DONE MESSAGE!");
-        let message: WriterTransferMessage<T> = WriterTransferMessage::Done;
-        let serialized = serde_json::to_vec(&message).unwrap();
-        self.write_all(&serialized)?;
-        self.flush()
+        self.framing.write_done(&mut self.pipe)
     }
 
     fn create_named_pipe_file(pipe_path: &Path) -> std::io::Result<File> {
@@ -91,7 +221,7 @@ impl<T: Serialize> WriterTransferProducer<T> {
     }
 }
 
-impl<T: Serialize> Write for WriterTransferProducer<T> {
+impl<T, F: TransferFraming<T>> Write for WriterTransferProducer<T, F> {
     fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
         self.pipe.write(buf)
     }
@@ -101,7 +231,7 @@ impl<T: Serialize> Write for WriterTransferProducer<T> {
     }
 }
 
-impl<T: Serialize> Drop for WriterTransferProducer<T> {
+impl<T, F: TransferFraming<T>> Drop for WriterTransferProducer<T, F> {
     fn drop(&mut self) {
         let pipe_path = self.pipe_path.clone();
         if let Err(err) = self.write_done_message() {
@@ -113,11 +243,12 @@ impl<T: Serialize> Drop for WriterTransferProducer<T> {
     }
 }
 
-pub struct WriterTransferConsumer {
+pub struct WriterTransferConsumer<F = JsonFraming> {
     pipe_path: PathBuf,
+    marker: PhantomData<F>,
 }
 
-impl WriterTransferConsumer {
+impl<F> WriterTransferConsumer<F> {
     pub fn new() -> std::io::Result<Self> {
         let pipe_path = crate::env::paradedb_transfer_pipe_path();
         // We'll remove the existing pipe_path, because we want to allow
@@ -129,12 +260,15 @@ impl WriterTransferConsumer {
                 )
             });
         }
-        Ok(Self { pipe_path })
+        Ok(Self {
+            pipe_path,
+            marker: PhantomData,
+        })
     }
 
-    pub fn read_stream<'a, T>(&'a mut self) -> WriterTransferMessageIterator<'a, T>
+    pub fn read_stream<T>(&mut self) -> F::Reader
     where
-        T: DeserializeOwned + 'a,
+        F: TransferFraming<T>,
     {
         // Wait for the client to create the pipe.
         while !self.pipe_path.exists() {
@@ -151,7 +285,6 @@ impl WriterTransferConsumer {
             });
 
         let reader = BufReader::new(pipe_file);
-        let stream = Deserializer::from_reader(reader).into_iter::<WriterTransferMessage<T>>();
-        WriterTransferMessageIterator { stream }
+        F::open_reader(reader)
     }
 }
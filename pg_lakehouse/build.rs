@@ -0,0 +1,10 @@
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    tonic_build::configure()
+        .build_server(false)
+        .file_descriptor_set_path(
+            std::path::PathBuf::from(std::env::var("OUT_DIR")?).join("schema_store_descriptor.bin"),
+        )
+        .compile(&["proto/schema_store.proto"], &["proto"])?;
+
+    Ok(())
+}
@@ -0,0 +1,26 @@
+use pgrx::*;
+
+use crate::datafusion::catalog::CATALOG_LIST;
+use crate::datafusion::metrics::CATALOG_METRICS;
+use crate::datafusion::remote::RemoteLakehouseCatalog;
+
+/// Renders table resolution throughput and latency for the lakehouse catalog in the Prometheus
+/// text exposition format, so operators can scrape how often `table_impl` hits its cache and how
+/// expensive opening a table fresh is for each format.
+#[pg_extern]
+pub fn catalog_metrics() -> String {
+    CATALOG_METRICS.render()
+}
+
+/// Connects to a remote `SchemaStore` endpoint and registers it into the process-wide lakehouse
+/// catalog list under `name`, so `name.schema.table` lookups route to the remote service instead
+/// of requiring local `CREATE FOREIGN TABLE` DDL for every table it already knows about.
+#[pg_extern]
+pub fn register_remote_catalog(name: &str, endpoint: &str) {
+    #[tokio::main(flavor = "current_thread")]
+    async fn register(name: &str, endpoint: &str) -> Result<(), crate::datafusion::catalog::CatalogError> {
+        RemoteLakehouseCatalog::register(&CATALOG_LIST, name, endpoint).await
+    }
+
+    register(name, endpoint).unwrap_or_else(|err| panic!("{}", err));
+}
@@ -1,7 +1,14 @@
-use async_std::sync::RwLock;
+use arc_swap::ArcSwap;
+use async_trait::async_trait;
+use once_cell::sync::Lazy;
+use datafusion::arrow::array::{RecordBatch, StringBuilder};
+use datafusion::arrow::datatypes::{DataType, Field, Schema as ArrowSchema};
 use datafusion::catalog::schema::SchemaProvider;
 use datafusion::catalog::{CatalogProvider, CatalogProviderList};
+use datafusion::common::exec_err;
 use datafusion::common::DataFusionError;
+use datafusion::datasource::{MemTable, TableProvider};
+use datafusion::error::Result;
 use pgrx::*;
 use std::{any::Any, collections::HashMap, sync::Arc};
 use supabase_wrappers::prelude::OptionsError;
@@ -9,24 +16,49 @@ use thiserror::Error;
 
 use crate::schema::attribute::SchemaError;
 
+use super::discovery::LakehouseDiscoverySchemaProvider;
 use super::provider::TableProviderError;
 
+/// The synthetic, read-only schema [`LakehouseCatalog::schema`] serves up for introspection --
+/// never one of the schemas registered via `register_schema`.
+const INFORMATION_SCHEMA: &str = "information_schema";
+
 #[derive(Clone)]
 pub struct LakehouseCatalog {
-    schemas: Arc<RwLock<HashMap<String, Arc<dyn SchemaProvider>>>>,
+    schemas: Arc<ArcSwap<HashMap<String, Arc<dyn SchemaProvider>>>>,
 }
 
 #[derive(Clone)]
 pub struct LakehouseCatalogList {
-    catalogs: Arc<RwLock<HashMap<String, Arc<dyn CatalogProvider>>>>,
+    catalogs: Arc<ArcSwap<HashMap<String, Arc<dyn CatalogProvider>>>>,
 }
 
 impl LakehouseCatalog {
     pub fn new() -> Self {
         Self {
-            schemas: Arc::new(RwLock::new(HashMap::new())),
+            schemas: Arc::new(ArcSwap::from_pointee(HashMap::new())),
         }
     }
+
+    /// Registers a [`LakehouseDiscoverySchemaProvider`] under `name`, so every "sub-directory"
+    /// under `base_path` is queryable as a table without a `CREATE FOREIGN TABLE` for it -- the
+    /// prefix-auto-discovery alternative to registering one schema per foreign server.
+    pub fn register_discovery_schema(
+        &self,
+        name: &str,
+        base_path: &str,
+        extension: &str,
+        ttl: std::time::Duration,
+        object_store: Arc<dyn object_store::ObjectStore>,
+    ) -> Result<Option<Arc<dyn SchemaProvider>>, DataFusionError> {
+        let schema = Arc::new(LakehouseDiscoverySchemaProvider::new(
+            base_path,
+            extension,
+            ttl,
+            object_store,
+        ));
+        self.register_schema(name, schema)
+    }
 }
 
 impl CatalogProvider for LakehouseCatalog {
@@ -34,67 +66,211 @@ impl CatalogProvider for LakehouseCatalog {
         self
     }
 
-    #[tokio::main(flavor = "current_thread")]
-    async fn register_schema(
+    // Following Garage's migration of its hot-path maps to `arc-swap`: a lookup just loads the
+    // current `Arc` and clones out of it, so `schema_names`/`schema` no longer take a lock or
+    // spin up a runtime on every query plan. Registration is the rarer path, so it pays for a
+    // copy-on-write clone of the whole map instead.
+    fn register_schema(
         &self,
         name: &str,
         schema: Arc<dyn SchemaProvider>,
     ) -> Result<Option<Arc<dyn SchemaProvider>>, DataFusionError> {
-        let mut schema_map = self.schemas.write().await;
-        schema_map.insert(name.to_owned(), schema.clone());
+        self.schemas.rcu(|schemas| {
+            let mut schemas = HashMap::clone(schemas);
+            schemas.insert(name.to_owned(), schema.clone());
+            schemas
+        });
         Ok(Some(schema))
     }
 
-    #[tokio::main(flavor = "current_thread")]
-    async fn schema_names(&self) -> Vec<String> {
-        let schemas = self.schemas.read().await;
-        schemas.keys().cloned().collect()
+    fn schema_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.schemas.load().keys().cloned().collect();
+        names.push(INFORMATION_SCHEMA.to_string());
+        names
     }
 
-    #[tokio::main(flavor = "current_thread")]
-    async fn schema(&self, name: &str) -> Option<Arc<dyn SchemaProvider>> {
-        let schemas = self.schemas.read().await;
-        match schemas.get(name) {
-            Some(schema) => Some(schema.clone() as Arc<dyn SchemaProvider>),
-            None => None,
+    fn schema(&self, name: &str) -> Option<Arc<dyn SchemaProvider>> {
+        if name == INFORMATION_SCHEMA {
+            return Some(Arc::new(InformationSchemaProvider::new(self.clone())));
         }
+
+        self.schemas.load().get(name).cloned()
+    }
+}
+
+/// Read-only `information_schema`, following GreptimeDB's catalog-manager refactor: rather than
+/// a real catalog of its own, `tables` and `columns` are [`MemTable`]s rebuilt from whichever
+/// schemas and tables `catalog` currently has registered, so `SHOW TABLES`, `\d`, and
+/// `SELECT * FROM information_schema.columns` all see live state instead of a stale snapshot.
+#[derive(Clone)]
+pub struct InformationSchemaProvider {
+    catalog: LakehouseCatalog,
+}
+
+impl InformationSchemaProvider {
+    fn new(catalog: LakehouseCatalog) -> Self {
+        Self { catalog }
+    }
+
+    fn tables_table(&self) -> Result<Arc<dyn TableProvider>, CatalogError> {
+        let mut schema_names = StringBuilder::new();
+        let mut table_names = StringBuilder::new();
+
+        for schema_name in self.catalog.schema_names() {
+            if schema_name == INFORMATION_SCHEMA {
+                continue;
+            }
+            let Some(schema) = self.catalog.schema(&schema_name) else {
+                continue;
+            };
+            for table_name in schema.table_names() {
+                schema_names.append_value(&schema_name);
+                table_names.append_value(&table_name);
+            }
+        }
+
+        let arrow_schema = Arc::new(ArrowSchema::new(vec![
+            Field::new("table_schema", DataType::Utf8, false),
+            Field::new("table_name", DataType::Utf8, false),
+        ]));
+        let batch = RecordBatch::try_new(
+            arrow_schema.clone(),
+            vec![
+                Arc::new(schema_names.finish()),
+                Arc::new(table_names.finish()),
+            ],
+        )?;
+
+        Ok(Arc::new(MemTable::try_new(arrow_schema, vec![vec![batch]])?))
+    }
+
+    async fn columns_table(&self) -> Result<Arc<dyn TableProvider>, CatalogError> {
+        let mut schema_names = StringBuilder::new();
+        let mut table_names = StringBuilder::new();
+        let mut column_names = StringBuilder::new();
+        let mut data_types = StringBuilder::new();
+
+        for schema_name in self.catalog.schema_names() {
+            if schema_name == INFORMATION_SCHEMA {
+                continue;
+            }
+            let Some(schema) = self.catalog.schema(&schema_name) else {
+                continue;
+            };
+            for table_name in schema.table_names() {
+                let Ok(Some(table)) = schema.table(&table_name).await else {
+                    continue;
+                };
+                for field in table.schema().fields() {
+                    schema_names.append_value(&schema_name);
+                    table_names.append_value(&table_name);
+                    column_names.append_value(field.name());
+                    data_types.append_value(field.data_type().to_string());
+                }
+            }
+        }
+
+        let arrow_schema = Arc::new(ArrowSchema::new(vec![
+            Field::new("table_schema", DataType::Utf8, false),
+            Field::new("table_name", DataType::Utf8, false),
+            Field::new("column_name", DataType::Utf8, false),
+            Field::new("data_type", DataType::Utf8, false),
+        ]));
+        let batch = RecordBatch::try_new(
+            arrow_schema.clone(),
+            vec![
+                Arc::new(schema_names.finish()),
+                Arc::new(table_names.finish()),
+                Arc::new(column_names.finish()),
+                Arc::new(data_types.finish()),
+            ],
+        )?;
+
+        Ok(Arc::new(MemTable::try_new(arrow_schema, vec![vec![batch]])?))
+    }
+}
+
+#[async_trait]
+impl SchemaProvider for InformationSchemaProvider {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn table_names(&self) -> Vec<String> {
+        vec!["tables".to_string(), "columns".to_string()]
+    }
+
+    async fn table(&self, name: &str) -> Result<Option<Arc<dyn TableProvider>>> {
+        let table = match name {
+            "tables" => self.tables_table(),
+            "columns" => self.columns_table().await,
+            _ => return Ok(None),
+        }
+        .unwrap_or_else(|err| panic!("{}", err));
+
+        Ok(Some(table))
+    }
+
+    fn table_exist(&self, name: &str) -> bool {
+        matches!(name, "tables" | "columns")
+    }
+
+    fn owner_name(&self) -> Option<&str> {
+        None
+    }
+
+    #[allow(unused_variables)]
+    fn register_table(
+        &self,
+        name: String,
+        table: Arc<dyn TableProvider>,
+    ) -> Result<Option<Arc<dyn TableProvider>>> {
+        exec_err!("information_schema is read-only")
+    }
+
+    #[allow(unused_variables)]
+    fn deregister_table(&self, name: &str) -> Result<Option<Arc<dyn TableProvider>>> {
+        exec_err!("information_schema is read-only")
     }
 }
 
 impl LakehouseCatalogList {
     pub fn new() -> Self {
         Self {
-            catalogs: Arc::new(RwLock::new(HashMap::new())),
+            catalogs: Arc::new(ArcSwap::from_pointee(HashMap::new())),
         }
     }
 }
 
+/// Process-wide registry of DataFusion catalogs, mirroring `metrics::CATALOG_METRICS`'s `Lazy`
+/// pattern: built once on first use and shared by every admin entry point that registers a
+/// catalog into it, such as [`super::remote::RemoteLakehouseCatalog::register`].
+pub static CATALOG_LIST: Lazy<LakehouseCatalogList> = Lazy::new(LakehouseCatalogList::new);
+
 impl CatalogProviderList for LakehouseCatalogList {
     fn as_any(&self) -> &dyn Any {
         self
     }
 
-    #[tokio::main(flavor = "current_thread")]
-    async fn register_catalog(
+    fn register_catalog(
         &self,
         name: String,
         catalog: Arc<dyn CatalogProvider>,
     ) -> Option<Arc<dyn CatalogProvider>> {
-        let mut catalog_map = self.catalogs.write().await;
-        catalog_map.insert(name, catalog.clone());
+        self.catalogs.rcu(|catalogs| {
+            let mut catalogs = HashMap::clone(catalogs);
+            catalogs.insert(name.clone(), catalog.clone());
+            catalogs
+        });
         Some(catalog)
     }
 
-    #[tokio::main(flavor = "current_thread")]
-    async fn catalog_names(&self) -> Vec<String> {
-        let catalog_map = self.catalogs.read().await;
-        catalog_map.keys().cloned().collect()
+    fn catalog_names(&self) -> Vec<String> {
+        self.catalogs.load().keys().cloned().collect()
     }
 
-    #[tokio::main(flavor = "current_thread")]
-    async fn catalog(&self, name: &str) -> Option<Arc<dyn CatalogProvider>> {
-        let catalog_map = self.catalogs.read().await;
-        catalog_map.get(name).cloned()
+    fn catalog(&self, name: &str) -> Option<Arc<dyn CatalogProvider>> {
+        self.catalogs.load().get(name).cloned()
     }
 }
 
@@ -115,6 +291,9 @@ pub enum CatalogError {
     #[error(transparent)]
     NulError(#[from] std::ffi::NulError),
 
+    #[error(transparent)]
+    ObjectStoreError(#[from] object_store::Error),
+
     #[error(transparent)]
     OptionsError(#[from] OptionsError),
 
@@ -127,9 +306,24 @@ pub enum CatalogError {
     #[error(transparent)]
     TableProviderError(#[from] TableProviderError),
 
+    #[error(transparent)]
+    TonicStatus(#[from] tonic::Status),
+
+    #[error(transparent)]
+    TonicTransportError(#[from] tonic::transport::Error),
+
+    #[error(transparent)]
+    UnityCatalogError(#[from] super::unity::UnityCatalogError),
+
     #[error(transparent)]
     Utf8Error(#[from] std::str::Utf8Error),
 
+    #[error("Remote schema store has no table named {0}")]
+    RemoteTableNotFound(String),
+
+    #[error("table format {0} is not supported as a DataFusion table provider; query it through its FDW instead")]
+    UnsupportedTableFormat(&'static str),
+
     #[allow(unused)]
     #[error("Unexpected error: Failed to downcast table provider to Delta table")]
     DowncastDeltaTable,
@@ -0,0 +1,159 @@
+use async_std::sync::RwLock;
+use async_trait::async_trait;
+use datafusion::catalog::schema::SchemaProvider;
+use datafusion::common::exec_err;
+use datafusion::datasource::TableProvider;
+use datafusion::error::Result;
+use object_store::path::Path as ObjectStorePath;
+use object_store::ObjectStore;
+use pgrx::warning;
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use super::catalog::CatalogError;
+use super::provider::*;
+
+/// A table built from a previous `object_store` listing, kept around until `ttl` elapses so a
+/// query that touches the same table twice in quick succession doesn't re-probe the store.
+struct CachedTable {
+    provider: Arc<dyn TableProvider + Send + Sync>,
+    cached_at: Instant,
+}
+
+/// Schema provider that enumerates tables from an object store prefix instead of requiring one
+/// `CREATE FOREIGN TABLE` per dataset. `table_names()` lists the common prefixes directly under
+/// `base_path` via `object_store::list_with_delimiter`, so every "sub-directory" becomes a table
+/// name. `table()` lazily builds a provider for a name the first time it's resolved: if
+/// `<base_path>/<name>/_delta_log/` exists it's treated as a Delta table, otherwise it falls back
+/// to a listing table over `extension` files. Built providers are cached in `tables`, keyed by
+/// table name rather than `Oid` since there's no foreign table relation to key on, and refreshed
+/// once `ttl` elapses so newly written partitions are picked up without a server restart.
+#[derive(Clone)]
+pub struct LakehouseDiscoverySchemaProvider {
+    base_path: String,
+    extension: String,
+    ttl: Duration,
+    object_store: Arc<dyn ObjectStore>,
+    tables: Arc<RwLock<HashMap<String, CachedTable>>>,
+}
+
+impl LakehouseDiscoverySchemaProvider {
+    pub fn new(base_path: &str, extension: &str, ttl: Duration, object_store: Arc<dyn ObjectStore>) -> Self {
+        Self {
+            base_path: base_path.trim_end_matches('/').to_string(),
+            extension: extension.to_string(),
+            ttl,
+            object_store,
+            tables: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    async fn discover_table_names(&self) -> Result<Vec<String>, CatalogError> {
+        let prefix = ObjectStorePath::from(format!("{}/", self.base_path));
+        let listing = self.object_store.list_with_delimiter(Some(&prefix)).await?;
+
+        Ok(listing
+            .common_prefixes
+            .iter()
+            .filter_map(|prefix| prefix.filename().map(|name| name.to_string()))
+            .collect())
+    }
+
+    /// A table counts as Delta if `<base_path>/<name>/_delta_log/` has anything under it at all;
+    /// an empty or missing `_delta_log` folder means the name is just a plain listing table.
+    async fn is_delta_table(&self, table_name: &str) -> Result<bool, CatalogError> {
+        let delta_log_path =
+            ObjectStorePath::from(format!("{}/{}/_delta_log/", self.base_path, table_name));
+        let listing = self
+            .object_store
+            .list_with_delimiter(Some(&delta_log_path))
+            .await?;
+
+        Ok(!listing.objects.is_empty())
+    }
+
+    async fn table_impl(
+        &self,
+        table_name: &str,
+    ) -> Result<Arc<dyn TableProvider + Send + Sync>, CatalogError> {
+        {
+            let tables = self.tables.read().await;
+            if let Some(cached) = tables.get(table_name) {
+                if cached.cached_at.elapsed() < self.ttl {
+                    return Ok(cached.provider.clone());
+                }
+            }
+        }
+
+        let table_path = format!("{}/{}", self.base_path, table_name);
+        let provider = if self.is_delta_table(table_name).await? {
+            create_delta_provider(&table_path, &self.extension).await?
+        } else {
+            create_listing_provider(&table_path, &self.extension).await?
+        };
+
+        let mut tables = self.tables.write().await;
+        tables.insert(
+            table_name.to_string(),
+            CachedTable {
+                provider: provider.clone(),
+                cached_at: Instant::now(),
+            },
+        );
+
+        Ok(provider)
+    }
+}
+
+#[async_trait]
+impl SchemaProvider for LakehouseDiscoverySchemaProvider {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    #[tokio::main(flavor = "current_thread")]
+    async fn table_names(&self) -> Vec<String> {
+        self.discover_table_names().await.unwrap_or_else(|err| {
+            warning!(
+                "failed to list lakehouse tables under {}: {err}",
+                self.base_path
+            );
+            Vec::new()
+        })
+    }
+
+    async fn table(&self, table_name: &str) -> Result<Option<Arc<dyn TableProvider>>> {
+        let provider = self
+            .table_impl(table_name)
+            .await
+            .unwrap_or_else(|err| panic!("{}", err));
+
+        Ok(Some(provider))
+    }
+
+    fn table_exist(&self, table_name: &str) -> bool {
+        self.table_names().iter().any(|name| name == table_name)
+    }
+
+    #[doc = r" Returns the owner of the Schema, default is None. This value is reported"]
+    #[doc = r" as part of `information_tables.schemata"]
+    fn owner_name(&self) -> Option<&str> {
+        None
+    }
+
+    #[allow(unused_variables)]
+    fn register_table(
+        &self,
+        name: String,
+        table: Arc<dyn TableProvider>,
+    ) -> Result<Option<Arc<dyn TableProvider>>> {
+        exec_err!("schema provider does not support registering tables")
+    }
+
+    #[allow(unused_variables)]
+    fn deregister_table(&self, name: &str) -> Result<Option<Arc<dyn TableProvider>>> {
+        exec_err!("schema provider does not support deregistering tables")
+    }
+}
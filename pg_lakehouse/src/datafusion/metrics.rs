@@ -0,0 +1,62 @@
+use once_cell::sync::Lazy;
+use prometheus::{
+    register_histogram_vec, register_int_counter_vec, Encoder, HistogramVec, IntCounterVec,
+    Registry, TextEncoder,
+};
+
+/// Metrics registry for the lakehouse catalog, mirroring `pg_bm25`'s `metrics.rs`: counters and
+/// histograms are registered once into a process-wide [`Registry`], labeled by table format
+/// (`listing`, `delta` or `unity`) and whether [`super::schema::LakehouseSchemaProvider::table_impl`]
+/// served the table from its in-memory cache or had to open it fresh.
+pub static CATALOG_METRICS: Lazy<CatalogMetrics> = Lazy::new(CatalogMetrics::register);
+
+pub struct CatalogMetrics {
+    registry: Registry,
+    pub table_resolve_total: IntCounterVec,
+    pub table_resolve_duration_seconds: HistogramVec,
+}
+
+impl CatalogMetrics {
+    fn register() -> Self {
+        let registry = Registry::new();
+
+        let table_resolve_total = register_int_counter_vec!(
+            "catalog_table_resolve_total",
+            "Total number of times the lakehouse catalog resolved a foreign table to a \
+             TableProvider",
+            &["format", "cache"]
+        )
+        .expect("catalog_table_resolve_total should register");
+        registry
+            .register(Box::new(table_resolve_total.clone()))
+            .expect("catalog_table_resolve_total should register with the registry");
+
+        let table_resolve_duration_seconds = register_histogram_vec!(
+            "catalog_table_resolve_duration_seconds",
+            "Latency of resolving a foreign table to a TableProvider, from opening the \
+             PostgreSQL relation through building or reusing the underlying provider",
+            &["format", "cache"]
+        )
+        .expect("catalog_table_resolve_duration_seconds should register");
+        registry
+            .register(Box::new(table_resolve_duration_seconds.clone()))
+            .expect("catalog_table_resolve_duration_seconds should register with the registry");
+
+        Self {
+            registry,
+            table_resolve_total,
+            table_resolve_duration_seconds,
+        }
+    }
+
+    /// Renders every metric in this registry in the Prometheus text exposition format.
+    pub fn render(&self) -> String {
+        let metric_families = self.registry.gather();
+        let mut buffer = Vec::new();
+        TextEncoder::new()
+            .encode(&metric_families, &mut buffer)
+            .expect("prometheus metrics should encode to text");
+
+        String::from_utf8(buffer).expect("prometheus text encoding should be valid utf8")
+    }
+}
@@ -0,0 +1,7 @@
+pub mod catalog;
+pub mod discovery;
+pub mod metrics;
+pub mod query;
+pub mod remote;
+pub mod schema;
+pub mod unity;
@@ -1,5 +1,6 @@
 use datafusion::error::DataFusionError;
 use datafusion::logical_expr::LogicalPlan;
+use datafusion::scalar::ScalarValue;
 use datafusion::sql::parser::DFParser;
 use datafusion::sql::planner::SqlToRel;
 use datafusion::sql::sqlparser::dialect::PostgreSqlDialect;
@@ -8,14 +9,26 @@ use thiserror::Error;
 
 use super::context::QueryContext;
 
-pub struct QueryString<'a>(pub &'a str);
+/// A query string, optionally paired with the bound values for its `$1, $2, ...` placeholders
+/// (as PREPARE/EXECUTE and extended-query-protocol clients supply them). When values are given,
+/// the `TryFrom` below substitutes them into the parsed plan with `LogicalPlan::with_param_values`.
+pub struct QueryString<'a>(pub &'a str, pub Option<Vec<ScalarValue>>);
+
+impl<'a> QueryString<'a> {
+    /// Builds a [`QueryString`] with no bound parameter values, for callers that only have a
+    /// plain query string on hand (e.g. the simple query protocol, which never supplies
+    /// `query_desc.params`).
+    pub fn new(query: &'a str) -> Self {
+        Self(query, None)
+    }
+}
 
 // Parses the query string into a DataFusion LogicalPlan
 impl TryFrom<QueryString<'_>> for LogicalPlan {
     type Error = QueryParserError;
 
     fn try_from(query: QueryString) -> Result<Self, Self::Error> {
-        let QueryString(query) = query;
+        let QueryString(query, param_values) = query;
 
         let dialect = PostgreSqlDialect {};
         let statement = DFParser::new_with_dialect(query, &dialect)?.parse_statement()?;
@@ -24,7 +37,12 @@ impl TryFrom<QueryString<'_>> for LogicalPlan {
         // Convert the AST into a logical plan
         let context_provider = QueryContext::new();
         let sql_to_rel = SqlToRel::new(&context_provider);
-        Ok(sql_to_rel.statement_to_plan(statement)?)
+        let logical_plan = sql_to_rel.statement_to_plan(statement)?;
+
+        Ok(match param_values {
+            Some(param_values) => logical_plan.with_param_values(param_values)?,
+            None => logical_plan,
+        })
     }
 }
 
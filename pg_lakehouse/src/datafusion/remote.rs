@@ -0,0 +1,207 @@
+use async_std::sync::RwLock;
+use async_trait::async_trait;
+use datafusion::catalog::schema::SchemaProvider;
+use datafusion::catalog::CatalogProvider;
+use datafusion::common::exec_err;
+use datafusion::datasource::TableProvider;
+use datafusion::error::Result;
+use pgrx::warning;
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tonic::transport::Channel;
+
+use super::catalog::{CatalogError, LakehouseCatalogList};
+use super::format::TableFormat;
+use super::provider::*;
+
+pub mod schema_store {
+    tonic::include_proto!("paradedb.lakehouse.schema_store.v1");
+}
+
+use schema_store::schema_store_client::SchemaStoreClient;
+use schema_store::{GetTableRequest, ListSchemasRequest, ListTablesRequest};
+
+/// `CatalogProvider` that sources its schema list from a remote `SchemaStore` gRPC service
+/// (modeled on seafowl's "clade" interface) instead of Postgres's own `pg_namespace`, so several
+/// `connect` instances can share one authoritative lakehouse catalog instead of each maintaining
+/// its own foreign-table DDL.
+#[derive(Clone)]
+pub struct RemoteLakehouseCatalog {
+    client: SchemaStoreClient<Channel>,
+}
+
+impl RemoteLakehouseCatalog {
+    pub async fn connect(endpoint: &str) -> Result<Self, CatalogError> {
+        let client = SchemaStoreClient::connect(endpoint.to_string()).await?;
+        Ok(Self { client })
+    }
+
+    /// Connects to `endpoint` and registers the resulting catalog into `catalog_list` under
+    /// `name`, the server-configured name Postgres-side FDW server setup looks the catalog up
+    /// under.
+    pub async fn register(
+        catalog_list: &LakehouseCatalogList,
+        name: &str,
+        endpoint: &str,
+    ) -> Result<(), CatalogError> {
+        let catalog = Self::connect(endpoint).await?;
+        catalog_list.register_catalog(name.to_string(), Arc::new(catalog));
+        Ok(())
+    }
+}
+
+impl CatalogProvider for RemoteLakehouseCatalog {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    #[tokio::main(flavor = "current_thread")]
+    async fn schema_names(&self) -> Vec<String> {
+        let mut client = self.client.clone();
+        match client.list_schemas(ListSchemasRequest {}).await {
+            Ok(response) => response.into_inner().schema_names,
+            Err(err) => {
+                warning!("failed to list remote lakehouse schemas: {err}");
+                Vec::new()
+            }
+        }
+    }
+
+    fn schema(&self, name: &str) -> Option<Arc<dyn SchemaProvider>> {
+        Some(Arc::new(RemoteLakehouseSchema::new(
+            self.client.clone(),
+            name,
+        )))
+    }
+
+    #[allow(unused_variables)]
+    #[tokio::main(flavor = "current_thread")]
+    async fn register_schema(
+        &self,
+        name: &str,
+        schema: Arc<dyn SchemaProvider>,
+    ) -> Result<Option<Arc<dyn SchemaProvider>>> {
+        exec_err!("remote lakehouse catalog does not support registering schemas locally")
+    }
+}
+
+/// `SchemaProvider` half of [`RemoteLakehouseCatalog`]: `table_names` and `table` are answered by
+/// calling `ListTables`/`GetTable` on the remote `SchemaStore`, and each returned descriptor is
+/// mapped through the same `TableFormat::from(...)` + `create_listing_provider`/
+/// `create_delta_provider` path `LakehouseSchemaProvider` uses for locally-declared tables.
+#[derive(Clone)]
+pub struct RemoteLakehouseSchema {
+    client: SchemaStoreClient<Channel>,
+    schema_name: String,
+    tables: Arc<RwLock<HashMap<String, Arc<dyn TableProvider + Send + Sync>>>>,
+}
+
+impl RemoteLakehouseSchema {
+    fn new(client: SchemaStoreClient<Channel>, schema_name: &str) -> Self {
+        Self {
+            client,
+            schema_name: schema_name.to_string(),
+            tables: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    async fn table_impl(
+        &self,
+        table_name: &str,
+    ) -> Result<Arc<dyn TableProvider + Send + Sync>, CatalogError> {
+        {
+            let tables = self.tables.read().await;
+            if let Some(table) = tables.get(table_name) {
+                return Ok(table.clone());
+            }
+        }
+
+        let mut client = self.client.clone();
+        let response = client
+            .get_table(GetTableRequest {
+                schema_name: self.schema_name.clone(),
+                table_name: table_name.to_string(),
+            })
+            .await?
+            .into_inner();
+
+        let descriptor = response
+            .table
+            .ok_or_else(|| CatalogError::RemoteTableNotFound(table_name.to_string()))?;
+
+        let provider = match TableFormat::from(descriptor.format.as_str()) {
+            TableFormat::None => {
+                create_listing_provider(&descriptor.path, &descriptor.extension).await?
+            }
+            // The remote `SchemaStore` already resolved the Unity catalog lookup (and any
+            // short-lived credentials) before handing back this descriptor, so from here a Unity
+            // table is just a Delta table at `descriptor.path`.
+            TableFormat::Delta | TableFormat::Unity => {
+                create_delta_provider(&descriptor.path, &descriptor.extension).await?
+            }
+            TableFormat::Iceberg => {
+                return Err(CatalogError::UnsupportedTableFormat("iceberg"));
+            }
+        };
+
+        let mut tables = self.tables.write().await;
+        tables.insert(table_name.to_string(), provider.clone());
+
+        Ok(provider)
+    }
+}
+
+#[async_trait]
+impl SchemaProvider for RemoteLakehouseSchema {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    #[tokio::main(flavor = "current_thread")]
+    async fn table_names(&self) -> Vec<String> {
+        let mut client = self.client.clone();
+        match client
+            .list_tables(ListTablesRequest {
+                schema_name: self.schema_name.clone(),
+            })
+            .await
+        {
+            Ok(response) => response.into_inner().table_names,
+            Err(err) => {
+                warning!(
+                    "failed to list tables for remote schema {}: {err}",
+                    self.schema_name
+                );
+                Vec::new()
+            }
+        }
+    }
+
+    async fn table(&self, table_name: &str) -> Result<Option<Arc<dyn TableProvider>>> {
+        let provider = self
+            .table_impl(table_name)
+            .await
+            .unwrap_or_else(|err| panic!("{}", err));
+
+        Ok(Some(provider))
+    }
+
+    fn table_exist(&self, table_name: &str) -> bool {
+        self.table_names().iter().any(|name| name == table_name)
+    }
+
+    #[allow(unused_variables)]
+    fn register_table(
+        &self,
+        name: String,
+        table: Arc<dyn TableProvider>,
+    ) -> Result<Option<Arc<dyn TableProvider>>> {
+        exec_err!("remote lakehouse schema does not support registering tables")
+    }
+
+    #[allow(unused_variables)]
+    fn deregister_table(&self, name: &str) -> Result<Option<Arc<dyn TableProvider>>> {
+        exec_err!("remote lakehouse schema does not support deregistering tables")
+    }
+}
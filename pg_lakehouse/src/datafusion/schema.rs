@@ -2,15 +2,16 @@ use async_std::sync::Mutex;
 use async_trait::async_trait;
 use datafusion::catalog::schema::SchemaProvider;
 use datafusion::common::exec_err;
-use datafusion::common::DataFusionError;
 use datafusion::datasource::TableProvider;
 use datafusion::error::Result;
 use deltalake::DeltaTable;
+use pgrx::spi::Spi;
 use pgrx::*;
 use std::any::Any;
 use std::collections::hash_map::Entry::{Occupied, Vacant};
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Instant;
 use supabase_wrappers::prelude::*;
 
 use crate::fdw::handler::*;
@@ -19,7 +20,9 @@ use crate::schema::attribute::*;
 
 use super::catalog::CatalogError;
 use super::format::*;
+use super::metrics::CATALOG_METRICS;
 use super::provider::*;
+use super::unity;
 
 #[derive(Clone)]
 pub struct LakehouseSchemaProvider {
@@ -41,6 +44,7 @@ impl LakehouseSchemaProvider {
         &self,
         table_name: &str,
     ) -> Result<Arc<dyn TableProvider + Send + Sync>, CatalogError> {
+        let start = Instant::now();
         let pg_relation = unsafe {
             PgRelation::open_with_name(table_name).unwrap_or_else(|err| {
                 panic!("{}", err);
@@ -48,14 +52,17 @@ impl LakehouseSchemaProvider {
         };
 
         let table_options = pg_relation.table_options()?;
-        let path = require_option(TableOption::Path.as_str(), &table_options)?;
         let extension = require_option(TableOption::Extension.as_str(), &table_options)?;
         let format = require_option_or(TableOption::Format.as_str(), &table_options, "");
+        let format_label = TableFormat::from(format).metrics_label();
         let mut tables = self.tables.lock().await;
 
+        let mut cache_label = "hit";
         let table: Arc<dyn TableProvider + Send + Sync> = match tables.entry(pg_relation.oid()) {
             Occupied(entry) => entry.into_mut().to_owned(),
             Vacant(entry) => {
+                cache_label = "miss";
+
                 let mut attribute_map: HashMap<usize, PgAttribute> = pg_relation
                     .tuple_desc()
                     .iter()
@@ -69,8 +76,37 @@ impl LakehouseSchemaProvider {
                     .collect();
 
                 let provider = match TableFormat::from(format) {
-                    TableFormat::None => create_listing_provider(path, extension).await?,
-                    TableFormat::Delta => create_delta_provider(path, extension).await?,
+                    TableFormat::None => {
+                        let path = require_option(TableOption::Path.as_str(), &table_options)?;
+                        create_listing_provider(path, extension).await?
+                    }
+                    TableFormat::Delta => {
+                        let path = require_option(TableOption::Path.as_str(), &table_options)?;
+                        create_delta_provider(path, extension).await?
+                    }
+                    TableFormat::Unity => {
+                        // Unlike `None`/`Delta`, the physical location isn't a table option --
+                        // it's resolved at query time from the Unity-compatible REST catalog at
+                        // `endpoint`, so the table can be declared by logical name alone.
+                        let endpoint = require_option("endpoint", &table_options)?;
+                        let identifier = require_option("identifier", &table_options)?;
+                        let location = unity::resolve_table_location(endpoint, identifier).await?;
+
+                        // `object_store`'s AWS client reads credentials from the environment, so
+                        // the short-lived ones Unity hands back just need to be in place before
+                        // `create_delta_provider` opens the table.
+                        for (key, value) in &location.credentials {
+                            std::env::set_var(key, value);
+                        }
+
+                        create_delta_provider(&location.storage_location, extension).await?
+                    }
+                    TableFormat::Iceberg => {
+                        // Iceberg tables are only queryable through the DuckDB FDW view (see
+                        // `crate::duckdb::iceberg::create_iceberg_view`); there's no DataFusion
+                        // `TableProvider` for them.
+                        return Err(CatalogError::UnsupportedTableFormat("iceberg"));
+                    }
                 };
 
                 for (index, field) in provider.schema().fields().iter().enumerate() {
@@ -84,7 +120,7 @@ impl LakehouseSchemaProvider {
         };
 
         let provider = match TableFormat::from(format) {
-            TableFormat::Delta => {
+            TableFormat::Delta | TableFormat::Unity => {
                 let mut delta_table = table
                     .as_any()
                     .downcast_ref::<DeltaTable>()
@@ -96,6 +132,15 @@ impl LakehouseSchemaProvider {
             _ => table.clone(),
         };
 
+        CATALOG_METRICS
+            .table_resolve_total
+            .with_label_values(&[format_label, cache_label])
+            .inc();
+        CATALOG_METRICS
+            .table_resolve_duration_seconds
+            .with_label_values(&[format_label, cache_label])
+            .observe(start.elapsed().as_secs_f64());
+
         Ok(provider)
     }
 }
@@ -106,32 +151,36 @@ impl SchemaProvider for LakehouseSchemaProvider {
         self
     }
 
-    // This function never gets called anywhere, so it's safe to leave unimplemented
+    // Lists every foreign table in `self.schema_name` whose server we recognize, by scanning
+    // `pg_catalog` rather than `self.tables` -- the latter only holds tables a query has already
+    // resolved via `table_impl`, so it can't be used to enumerate what exists up front.
     fn table_names(&self) -> Vec<String> {
-        todo!("table_names not implemented")
+        const QUERY: &str = "SELECT c.relname FROM pg_catalog.pg_foreign_table ft \
+             JOIN pg_catalog.pg_class c ON c.oid = ft.ftrelid \
+             JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace \
+             WHERE n.nspname = $1";
+
+        Spi::connect(|client| {
+            client
+                .select(QUERY, None, &[self.schema_name.clone().into()])
+                .map(|tuptable| {
+                    tuptable
+                        .into_iter()
+                        .filter_map(|row| row.get::<String>(1).ok().flatten())
+                        .filter(|table_name| self.table_exist(table_name))
+                        .collect()
+                })
+                .unwrap_or_else(|err| panic!("{}", err))
+        })
     }
 
-    fn table<'life0, 'life1, 'async_trait>(
-        &'life0 self,
-        table_name: &'life1 str,
-    ) -> Pin<
-        Box<
-            dyn Future<Output = Result<Option<Arc<dyn TableProvider>>, DataFusionError>>
-                + Send
-                + 'async_trait,
-        >,
-    >
-    where
-        Self: 'async_trait,
-        'life0: 'async_trait,
-        'life1: 'async_trait,
-    {
-        Box::pin(async move {
-            let table = self
-                .table_impl(table_name)
-                .unwrap_or_else(|err| panic!("{}", err));
-
+    async fn table(&self, table_name: &str) -> Result<Option<Arc<dyn TableProvider>>> {
+        let table = self
+            .table_impl(table_name)
+            .await
+            .unwrap_or_else(|err| panic!("{}", err));
 
+        Ok(Some(table))
     }
 
     fn table_exist(&self, table_name: &str) -> bool {
@@ -0,0 +1,89 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::time::Duration;
+use thiserror::Error;
+
+/// Physical storage location and short-lived storage credentials for a table resolved from a
+/// Unity-compatible REST catalog, ready to hand off to
+/// [`super::provider::create_delta_provider`].
+pub struct UnityTableLocation {
+    pub storage_location: String,
+    pub credentials: HashMap<String, String>,
+}
+
+#[derive(Deserialize)]
+struct GetTableResponse {
+    storage_location: String,
+}
+
+#[derive(Deserialize)]
+struct TemporaryTableCredentialsResponse {
+    #[serde(default)]
+    aws_temp_credentials: Option<AwsTempCredentials>,
+}
+
+#[derive(Deserialize)]
+struct AwsTempCredentials {
+    access_key_id: String,
+    secret_access_key: String,
+    session_token: String,
+}
+
+/// Minimal client for delta-rs's experimental Unity Catalog REST API: given `endpoint` (the
+/// catalog's base URL) and a `catalog.schema.table` identifier, resolves the table's physical
+/// storage location and short-lived storage credentials, so a foreign table can be declared by
+/// logical name instead of a hard-coded bucket path.
+pub async fn resolve_table_location(
+    endpoint: &str,
+    identifier: &str,
+) -> Result<UnityTableLocation, UnityCatalogError> {
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(30))
+        .build()?;
+
+    let table: GetTableResponse = client
+        .get(format!(
+            "{endpoint}/api/2.1/unity-catalog/tables/{identifier}"
+        ))
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+
+    let credentials_response: TemporaryTableCredentialsResponse = client
+        .post(format!(
+            "{endpoint}/api/2.1/unity-catalog/temporary-table-credentials"
+        ))
+        .json(&serde_json::json!({ "table_id": identifier, "operation": "READ" }))
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+
+    let credentials = credentials_response
+        .aws_temp_credentials
+        .map(|creds| {
+            HashMap::from([
+                ("AWS_ACCESS_KEY_ID".to_string(), creds.access_key_id),
+                (
+                    "AWS_SECRET_ACCESS_KEY".to_string(),
+                    creds.secret_access_key,
+                ),
+                ("AWS_SESSION_TOKEN".to_string(), creds.session_token),
+            ])
+        })
+        .unwrap_or_default();
+
+    Ok(UnityTableLocation {
+        storage_location: table.storage_location,
+        credentials,
+    })
+}
+
+#[derive(Error, Debug)]
+pub enum UnityCatalogError {
+    #[error(transparent)]
+    Reqwest(#[from] reqwest::Error),
+}
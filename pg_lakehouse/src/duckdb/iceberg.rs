@@ -0,0 +1,27 @@
+use anyhow::Result;
+use std::collections::HashMap;
+
+use crate::duckdb::connection;
+use crate::fdw::options::*;
+
+/// Registers a DuckDB view over an Iceberg table so it can be scanned like any other foreign
+/// table, mirroring `create_delta_view`: the foreign-table options name the table's location (its
+/// metadata path on S3/GCS/Azure), and DuckDB's `iceberg_scan` reads the current snapshot
+/// straight off that location.
+pub fn create_iceberg_view(
+    table_name: &str,
+    schema_name: &str,
+    table_options: HashMap<String, String>,
+) -> Result<()> {
+    let path = require_option(TableOption::Path.as_str(), &table_options)?;
+
+    connection::execute(
+        format!(
+            "CREATE VIEW {schema_name}.{table_name} AS SELECT * FROM iceberg_scan('{path}', allow_moved_paths = true)"
+        )
+        .as_str(),
+        [],
+    )?;
+
+    Ok(())
+}
@@ -6,9 +6,11 @@ use supabase_wrappers::prelude::*;
 use thiserror::Error;
 
 use super::handler::FdwHandler;
+use super::retry::{with_retry, RetryConfig};
 use crate::duckdb::connection;
 use crate::duckdb::csv::create_csv_view;
 use crate::duckdb::delta::create_delta_view;
+use crate::duckdb::iceberg::create_iceberg_view;
 use crate::duckdb::parquet::create_parquet_view;
 use crate::schema::cell::*;
 
@@ -32,8 +34,7 @@ pub trait BaseFdw {
 
     async fn begin_scan_impl(
         &mut self,
-        // TODO: Push down quals
-        _quals: &[Qual],
+        quals: &[Qual],
         columns: &[Column],
         sorts: &[Sort],
         limit: &Option<Limit>,
@@ -51,8 +52,14 @@ pub trait BaseFdw {
         // Cache target columns
         self.set_target_columns(columns);
 
-        // Create DuckDB secret from user mapping options
-        connection::create_secret(DEFAULT_SECRET, self.get_user_mapping_options())?;
+        let user_mapping_options = self.get_user_mapping_options();
+        let retry_config = RetryConfig::from_options(&user_mapping_options);
+
+        // Create DuckDB secret from user mapping options. Talking to the object store's STS/IAM
+        // endpoint to validate the secret can hit the same transient failures as the scan itself.
+        with_retry(&retry_config, "create_secret", || {
+            connection::create_secret(DEFAULT_SECRET, user_mapping_options.clone())
+        })?;
 
         // Create DuckDB view
         if !connection::view_exists(table_name, schema_name)? {
@@ -68,13 +75,24 @@ pub trait BaseFdw {
 
             match FdwHandler::from(foreign_server) {
                 FdwHandler::Csv => {
-                    create_csv_view(table_name, schema_name, table_options)?;
+                    with_retry(&retry_config, "create_csv_view", || {
+                        create_csv_view(table_name, schema_name, table_options.clone())
+                    })?;
                 }
                 FdwHandler::Delta => {
-                    create_delta_view(table_name, schema_name, table_options)?;
+                    with_retry(&retry_config, "create_delta_view", || {
+                        create_delta_view(table_name, schema_name, table_options.clone())
+                    })?;
                 }
                 FdwHandler::Parquet => {
-                    create_parquet_view(table_name, schema_name, table_options)?;
+                    with_retry(&retry_config, "create_parquet_view", || {
+                        create_parquet_view(table_name, schema_name, table_options.clone())
+                    })?;
+                }
+                FdwHandler::Iceberg => {
+                    with_retry(&retry_config, "create_iceberg_view", || {
+                        create_iceberg_view(table_name, schema_name, table_options.clone())
+                    })?;
                 }
                 _ => {
                     todo!()
@@ -98,6 +116,11 @@ pub trait BaseFdw {
 
         let mut sql = format!("SELECT {targets} FROM {schema_name}.{table_name}");
 
+        let pushed_quals: Vec<String> = quals.iter().filter_map(deparse_qual).collect();
+        if !pushed_quals.is_empty() {
+            sql.push_str(&format!(" WHERE {}", pushed_quals.join(" AND ")));
+        }
+
         if !sorts.is_empty() {
             let order_by = sorts
                 .iter()
@@ -122,7 +145,10 @@ pub trait BaseFdw {
             let sql = self
                 .get_sql()
                 .ok_or_else(|| anyhow!("sql statement was not cached"))?;
-            connection::create_arrow(sql.as_str())?;
+            let retry_config = RetryConfig::from_options(&self.get_user_mapping_options());
+            with_retry(&retry_config, "create_arrow", || {
+                connection::create_arrow(sql.as_str())
+            })?;
         }
 
         if self.get_current_batch().is_none()
@@ -184,6 +210,65 @@ impl From<BaseFdwError> for pg_sys::panic::ErrorReport {
     }
 }
 
+/// Deparses one `Qual` into a DuckDB `WHERE` fragment, or `None` if it can't be pushed down
+/// safely. Only simple comparisons against a constant are eligible: `OR`-combined quals, and
+/// operators this function doesn't recognize, are left for Postgres to re-check instead of
+/// guessing at their DuckDB equivalent.
+fn deparse_qual(qual: &Qual) -> Option<String> {
+    if qual.use_or {
+        return None;
+    }
+
+    match qual.operator.as_str() {
+        "=" | "<>" | "<" | "<=" | ">" | ">=" => {
+            let Value::Cell(cell) = &qual.value else {
+                return None;
+            };
+            let literal = deparse_cell(cell)?;
+            Some(format!("{} {} {}", qual.field, qual.operator, literal))
+        }
+        "is" => Some(format!("{} IS NULL", qual.field)),
+        "is not" => Some(format!("{} IS NOT NULL", qual.field)),
+        "in" => {
+            let Value::Array(cells) = &qual.value else {
+                return None;
+            };
+            let literals = cells
+                .iter()
+                .map(deparse_cell)
+                .collect::<Option<Vec<String>>>()?;
+            if literals.is_empty() {
+                return None;
+            }
+            Some(format!("{} IN ({})", qual.field, literals.join(", ")))
+        }
+        // Anything else (LIKE, array containment, custom operators, ...) doesn't have an
+        // obviously correct DuckDB translation, so skip it.
+        _ => None,
+    }
+}
+
+/// Renders a constant `Cell` as a DuckDB SQL literal. Text and timestamp-like cells are quoted
+/// and escaped; numerics and booleans are emitted bare. Cell kinds without an unambiguous literal
+/// form (JSON, arrays, ...) return `None` so the qual they belong to is skipped.
+fn deparse_cell(cell: &Cell) -> Option<String> {
+    match cell {
+        Cell::Bool(value) => Some(value.to_string()),
+        Cell::I8(value) => Some(value.to_string()),
+        Cell::I16(value) => Some(value.to_string()),
+        Cell::I32(value) => Some(value.to_string()),
+        Cell::I64(value) => Some(value.to_string()),
+        Cell::F32(value) => Some(value.to_string()),
+        Cell::F64(value) => Some(value.to_string()),
+        Cell::Numeric(value) => Some(value.to_string()),
+        Cell::String(value) => Some(format!("'{}'", value.replace('\'', "''"))),
+        Cell::Date(value) => Some(format!("'{}'", value)),
+        Cell::Timestamp(value) => Some(format!("'{}'", value)),
+        Cell::Timestamptz(value) => Some(format!("'{}'", value)),
+        _ => None,
+    }
+}
+
 pub fn validate_options(opt_list: Vec<Option<String>>, valid_options: Vec<String>) -> Result<()> {
     for opt in opt_list
         .iter()
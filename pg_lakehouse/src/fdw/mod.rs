@@ -0,0 +1,9 @@
+pub mod base;
+pub mod retry;
+
+// `base` also references a sibling `handler` module (`FdwHandler`, matched on by
+// `BaseFdw::begin_scan_impl` and `LakehouseSchemaProvider::table_exist` to pick the Csv/Delta/
+// Parquet/Iceberg view-creation path) that was never added to this tree. Its `From<*mut
+// pg_sys::ForeignServer>` impl would need to read back which physical foreign-data-wrapper name a
+// `CREATE SERVER` was declared against, which isn't recoverable from anything else in this crate --
+// left unimplemented rather than guessed at.
@@ -0,0 +1,155 @@
+use anyhow::Result;
+use pgrx::log;
+use std::collections::HashMap;
+use std::io;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Backoff parameters for [`with_retry`], read from user-mapping options so operators can tune
+/// retry behavior without a GUC (there's no GUC-registration module in this crate to hang one off
+/// of). Foreign-server options are not consulted.
+#[derive(Copy, Clone, Debug)]
+pub struct RetryConfig {
+    base_delay: Duration,
+    max_delay: Duration,
+    max_elapsed: Duration,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(30),
+            max_elapsed: Duration::from_secs(60),
+        }
+    }
+}
+
+impl RetryConfig {
+    /// Reads `retry_base_delay_ms` / `retry_max_delay_ms` / `retry_max_elapsed_secs` out of
+    /// `options` (the `BaseFdw` scan passes its `CREATE USER MAPPING ... OPTIONS (...)`), falling
+    /// back to [`RetryConfig::default`] for anything missing or unparseable.
+    pub fn from_options(options: &HashMap<String, String>) -> Self {
+        let defaults = Self::default();
+
+        Self {
+            base_delay: options
+                .get("retry_base_delay_ms")
+                .and_then(|value| value.parse().ok())
+                .map(Duration::from_millis)
+                .unwrap_or(defaults.base_delay),
+            max_delay: options
+                .get("retry_max_delay_ms")
+                .and_then(|value| value.parse().ok())
+                .map(Duration::from_millis)
+                .unwrap_or(defaults.max_delay),
+            max_elapsed: options
+                .get("retry_max_elapsed_secs")
+                .and_then(|value| value.parse().ok())
+                .map(Duration::from_secs)
+                .unwrap_or(defaults.max_elapsed),
+        }
+    }
+}
+
+/// Runs `operation`, retrying with capped exponential backoff plus jitter while the error it
+/// returns looks transient (per [`is_transient`]) and the total time spent hasn't yet exceeded
+/// `config.max_elapsed`. A non-transient error, or one that's still failing once the elapsed-time
+/// budget runs out, is returned immediately.
+///
+/// `description` is only used to label the retry log message (e.g. `"create_secret"`).
+pub fn with_retry<T>(
+    config: &RetryConfig,
+    description: &str,
+    mut operation: impl FnMut() -> Result<T>,
+) -> Result<T> {
+    let started_at = Instant::now();
+    let mut delay = config.base_delay;
+
+    loop {
+        match operation() {
+            Ok(value) => return Ok(value),
+            Err(err) if is_transient(&err) && started_at.elapsed() < config.max_elapsed => {
+                let sleep_for = jitter(delay.min(config.max_delay));
+                log!(
+                    "pg_lakehouse {description} failed transiently ({err}), retrying in \
+                     {sleep_for:?} (elapsed {:?}/{:?})",
+                    started_at.elapsed(),
+                    config.max_elapsed
+                );
+                std::thread::sleep(sleep_for);
+                delay = (delay * 2).min(config.max_delay);
+            }
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+/// Inspects the error chain (the DuckDB/anyhow message text and any underlying [`io::Error`]) for
+/// signs that the failure was a transient hiccup talking to the object store -- connection
+/// refused/reset/aborted, DNS failures, and HTTP 500/502/503/504/429/timeout responses (other
+/// 5xx statuses are not treated as transient) -- as opposed to
+/// something retrying won't fix, like a bad credential, a missing object, or a malformed option.
+fn is_transient(err: &anyhow::Error) -> bool {
+    for cause in err.chain() {
+        if let Some(io_err) = cause.downcast_ref::<io::Error>() {
+            if matches!(
+                io_err.kind(),
+                io::ErrorKind::ConnectionRefused
+                    | io::ErrorKind::ConnectionReset
+                    | io::ErrorKind::ConnectionAborted
+                    | io::ErrorKind::TimedOut
+                    | io::ErrorKind::NotConnected
+                    | io::ErrorKind::Interrupted
+            ) {
+                return true;
+            }
+        }
+
+        let message = cause.to_string().to_lowercase();
+        if message.contains("connection refused")
+            || message.contains("connection reset")
+            || message.contains("connection aborted")
+            || message.contains("timed out")
+            || message.contains("timeout")
+            || message.contains("dns")
+            || message.contains("temporary failure")
+            || message.contains("too many requests")
+            || message.contains("429")
+            || message.contains("500")
+            || message.contains("502")
+            || message.contains("503")
+            || message.contains("504")
+        {
+            return true;
+        }
+
+        if message.contains("unauthorized")
+            || message.contains("forbidden")
+            || message.contains("403")
+            || message.contains("404")
+            || message.contains("not found")
+            || message.contains("invalid option")
+            || message.contains("malformed")
+        {
+            return false;
+        }
+    }
+
+    false
+}
+
+/// Adds up to +/-25% jitter to `delay`, seeded off the low bits of the current time so repeated
+/// retries by the same backend don't all wake up in lockstep -- this crate has no RNG dependency
+/// to reach for instead.
+fn jitter(delay: Duration) -> Duration {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|since_epoch| since_epoch.subsec_nanos())
+        .unwrap_or_default() as u64;
+    let spread = delay.as_millis() as u64 / 4;
+    if spread == 0 {
+        return delay;
+    }
+    let offset = nanos % (2 * spread + 1);
+    delay + Duration::from_millis(offset) - Duration::from_millis(spread)
+}
@@ -11,6 +11,13 @@ pub struct FileExtension(pub String);
 pub enum TableFormat {
     None,
     Delta,
+    /// A Delta table whose storage location and credentials aren't known up front, but are
+    /// resolved at query time from a Unity-compatible REST catalog (see
+    /// [`super::datafusion::unity`]) given a `catalog.schema.table` identifier.
+    Unity,
+    /// An Iceberg table, scanned through the DuckDB FDW path (see
+    /// [`crate::duckdb::iceberg::create_iceberg_view`]) rather than a DataFusion table provider.
+    Iceberg,
 }
 
 impl TableFormat {
@@ -18,6 +25,20 @@ impl TableFormat {
         match self {
             Self::None => "",
             Self::Delta => "delta",
+            Self::Unity => "unity",
+            Self::Iceberg => "iceberg",
+        }
+    }
+
+    /// Label for the `format` dimension of the `catalog_table_resolve_*` metrics. Distinct from
+    /// [`Self::as_str`] because `None` round-trips through the `format` table option as `""`,
+    /// which isn't a meaningful Prometheus label value.
+    pub fn metrics_label(&self) -> &'static str {
+        match self {
+            Self::None => "listing",
+            Self::Delta => "delta",
+            Self::Unity => "unity",
+            Self::Iceberg => "iceberg",
         }
     }
 
@@ -25,12 +46,14 @@ impl TableFormat {
         match format {
             "" => Self::None,
             "delta" => Self::Delta,
+            "unity" => Self::Unity,
+            "iceberg" => Self::Iceberg,
             _ => Self::None,
         }
     }
 
     pub fn iter() -> impl Iterator<Item = Self> {
-        [Self::None, Self::Delta].into_iter()
+        [Self::None, Self::Delta, Self::Unity, Self::Iceberg].into_iter()
     }
 }
 
@@ -0,0 +1,9 @@
+use pgrx::prelude::*;
+
+mod api;
+mod datafusion;
+mod duckdb;
+mod fdw;
+mod format;
+
+pgrx::pg_module_magic!();
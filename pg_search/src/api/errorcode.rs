@@ -0,0 +1,58 @@
+use pgrx::pg_sys;
+use pgrx::pg_sys::PgSqlErrorCode;
+
+use crate::index::search::SearchIndexError;
+
+/// A stable, machine-readable identifier for a logical failure, independent of the underlying
+/// error's `Display` text (which is free to change wording across versions). Modeled on
+/// Meilisearch's error-code layer: clients and retry logic branch on [`ErrorCode::code`] instead
+/// of parsing messages, while [`ErrorCode::sqlstate`] gives Postgres drivers the matching
+/// `SQLSTATE` class -- `22xxx`/`42xxx` for errors the caller can fix by changing their input,
+/// `XX000` for internal failures worth retrying or escalating.
+pub trait ErrorCode {
+    /// A short, stable snake_case identifier, e.g. `"schema_invalid"`.
+    fn code(&self) -> &'static str;
+
+    /// The Postgres `SQLSTATE` class this error should be reported under.
+    fn sqlstate(&self) -> PgSqlErrorCode;
+}
+
+/// Formats `err` as `[code] message`, the consistent detail shape every error surfaced through
+/// this layer carries, so drivers can split on the leading `[...]` without re-deriving the code.
+pub fn coded_message(err: &(impl ErrorCode + std::fmt::Display)) -> String {
+    format!("[{}] {err}", err.code())
+}
+
+impl ErrorCode for SearchIndexError {
+    fn code(&self) -> &'static str {
+        match self {
+            SearchIndexError::SchemaError(_) => "schema_invalid",
+            SearchIndexError::WriterIndexError(_) => "writer_lock_failed",
+            SearchIndexError::TantivyError(_) => "index_not_accessible",
+            SearchIndexError::IOError(_) => "index_not_accessible",
+            SearchIndexError::SerdeError(_) => "schema_invalid",
+            SearchIndexError::AnyhowError(_) => "index_not_accessible",
+            SearchIndexError::KeyFieldMissing => "key_field_missing",
+        }
+    }
+
+    fn sqlstate(&self) -> PgSqlErrorCode {
+        match self {
+            SearchIndexError::SchemaError(_)
+            | SearchIndexError::SerdeError(_)
+            | SearchIndexError::KeyFieldMissing => PgSqlErrorCode::ERRCODE_INVALID_PARAMETER_VALUE,
+            SearchIndexError::WriterIndexError(_)
+            | SearchIndexError::TantivyError(_)
+            | SearchIndexError::IOError(_)
+            | SearchIndexError::AnyhowError(_) => PgSqlErrorCode::ERRCODE_INTERNAL_ERROR,
+        }
+    }
+}
+
+impl From<SearchIndexError> for pg_sys::panic::ErrorReport {
+    fn from(value: SearchIndexError) -> Self {
+        let sqlstate = value.sqlstate();
+        let message = coded_message(&value);
+        pg_sys::panic::ErrorReport::new(sqlstate, message, "")
+    }
+}
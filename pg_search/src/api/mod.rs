@@ -0,0 +1,23 @@
+use pgrx::*;
+
+use crate::index::search::check_index_health;
+
+pub mod errorcode;
+
+/// Reports whether `index_name` currently opens cleanly, without rebuilding or otherwise
+/// mutating it. Returns `true` for a healthy index; otherwise logs the underlying error via
+/// `NOTICE` and returns `false`. Lets operators check for corruption before deciding between
+/// `paradedb.auto_recover_corrupt_index` and a manual `REINDEX`.
+#[pg_extern]
+pub fn index_health(index_name: &str) -> bool {
+    let indexrel = PgRelation::open_with_name_and_share_lock(index_name)
+        .unwrap_or_else(|err| panic!("could not open index {index_name}: {err}"));
+
+    match check_index_health(&indexrel) {
+        Ok(()) => true,
+        Err(err) => {
+            notice!("pg_search: index {index_name} did not open cleanly: {err}");
+            false
+        }
+    }
+}
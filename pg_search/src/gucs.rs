@@ -0,0 +1,39 @@
+use pgrx::{GucContext, GucFlags, GucRegistry, GucSetting};
+
+static AUTO_RECOVER_CORRUPT_INDEX: GucSetting<bool> = GucSetting::<bool>::new(false);
+static SEARCH_PARALLELISM: GucSetting<i32> = GucSetting::<i32>::new(1);
+
+pub fn init() {
+    GucRegistry::define_bool_guc(
+        "paradedb.auto_recover_corrupt_index",
+        "Automatically rebuild an index from scratch when it's found to be corrupt on open",
+        "When enabled, opening an index that fails with a genuine corruption error snapshots \
+         the corrupt directory and rebuilds the index empty instead of failing the query. When \
+         disabled (the default), a corrupt index always surfaces as an error.",
+        &AUTO_RECOVER_CORRUPT_INDEX,
+        GucContext::Userset,
+        GucFlags::default(),
+    );
+
+    GucRegistry::define_int_guc(
+        "paradedb.search_parallelism",
+        "Number of threads tantivy uses to execute a single search query",
+        "When greater than 1, searches over an index with at least two segments run on a shared \
+         multi-thread Executor with this many worker threads instead of single_thread. The pool \
+         is built once per backend, on first use, from the value in effect at that moment; \
+         higher values reduce search latency at the cost of more CPU per query.",
+        &SEARCH_PARALLELISM,
+        1,
+        1024,
+        GucContext::Userset,
+        GucFlags::default(),
+    );
+}
+
+pub fn auto_recover_corrupt_index() -> bool {
+    AUTO_RECOVER_CORRUPT_INDEX.get()
+}
+
+pub fn search_parallelism() -> &'static GucSetting<i32> {
+    &SEARCH_PARALLELISM
+}
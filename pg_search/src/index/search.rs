@@ -32,9 +32,10 @@ use crate::schema::{
 };
 use anyhow::Result;
 use once_cell::sync::Lazy;
-use pgrx::{pg_sys, PgRelation};
+use pgrx::{pg_sys, warning, PgLogLevel, PgRelation};
 use serde::Serialize;
 use std::num::NonZeroUsize;
+use std::path::Path;
 use tantivy::directory::DirectoryClone;
 use tantivy::query::Query;
 use tantivy::schema::Schema;
@@ -45,8 +46,22 @@ use tracing::trace;
 use url::quirks::search;
 
 /// PostgreSQL operates in a process-per-client model, meaning every client connection
-/// to PostgreSQL results in a new backend process being spawned on the PostgreSQL server.
-pub static mut SEARCH_EXECUTOR: Lazy<Executor> = Lazy::new(Executor::single_thread);
+/// to PostgreSQL results in a new backend process being spawned on the PostgreSQL server. This
+/// pool is built once per backend from `paradedb.search_parallelism` and reused by every query
+/// that runs in it, so a backend only ever pays the thread pool's spin-up cost once.
+pub static mut SEARCH_EXECUTOR: Lazy<Executor> = Lazy::new(|| {
+    let parallelism = gucs::search_parallelism().get();
+    if parallelism <= 1 {
+        return Executor::single_thread();
+    }
+    Executor::multi_thread(parallelism, "pg_search-search-")
+        .expect("search executor thread pool should initialize")
+});
+
+/// Below this many segments, per-segment collection parallelism isn't worth a thread pool's
+/// spin-up cost, so [`SearchIndex::executor`] keeps small indexes on a single-thread executor
+/// regardless of `paradedb.search_parallelism`.
+const MIN_SEGMENTS_FOR_PARALLEL_EXECUTOR: usize = 2;
 
 pub enum WriterResources {
     CreateIndex,
@@ -159,13 +174,40 @@ impl SearchIndex {
         let mut handler =
             ChannelRequestHandler::open(tantivy_dir, index_oid, resp_sender, req_receiver);
 
-        let underlying_index = handler
+        let open_result = handler
             .wait_for(|| {
                 let mut index = opener(channel_dir.box_clone(), &schema)?;
                 SearchIndex::setup_tokenizers(&mut index, &schema);
                 tantivy::Result::Ok(index)
             })
-            .expect("scoped thread should not fail")?;
+            .expect("scoped thread should not fail");
+
+        let underlying_index = match open_result {
+            Ok(index) => index,
+            Err(err) if Self::is_corruption(&err) && gucs::auto_recover_corrupt_index() => {
+                warning!(
+                    "pg_search: index {index_oid:?} failed to open ({err}), rebuilding an empty \
+                     index in its place. Run REINDEX to repopulate it."
+                );
+                Self::snapshot_corrupt_directory(index_oid, channel_dir.box_clone().as_ref());
+                handler
+                    .wait_for(|| {
+                        let settings = IndexSettings {
+                            docstore_compress_dedicated_thread: false,
+                            ..IndexSettings::default()
+                        };
+                        let mut index =
+                            Index::create(channel_dir.box_clone(), schema.schema.clone(), settings)?;
+                        SearchIndex::setup_tokenizers(&mut index, &schema);
+                        tantivy::Result::Ok(index)
+                    })
+                    .expect("scoped thread should not fail")?
+            }
+            Err(err) => {
+                unsafe { pg_sys::UnlockReleaseBuffer(lock) };
+                return Err(err.into());
+            }
+        };
 
         unsafe { pg_sys::UnlockReleaseBuffer(lock) };
 
@@ -177,6 +219,68 @@ impl SearchIndex {
         })
     }
 
+    /// Returns `true` when `err` indicates the on-disk index is actually corrupt (a bad META
+    /// block, an unreadable segment) rather than some transient or unrelated failure. Only
+    /// these are worth auto-recovering from; anything else should keep failing loudly.
+    ///
+    /// `InvalidArgument` deliberately doesn't count: tantivy also returns it for things like a
+    /// malformed query or an out-of-range field configuration, neither of which is fixed by
+    /// throwing away the index and rebuilding it empty.
+    fn is_corruption(err: &tantivy::TantivyError) -> bool {
+        matches!(err, tantivy::TantivyError::DataCorruption(_))
+    }
+
+    /// Best-effort copy of the current (corrupt) directory's managed files to a side location on
+    /// disk, before we replace the directory with a fresh empty index. `directory` only
+    /// implements tantivy's generic [`Directory`] trait (its real storage is Postgres relation
+    /// buffers, not a filesystem), so the copy goes through `atomic_read` against `.managed.json`
+    /// -- the file tantivy itself uses to track every file a directory owns -- rather than any
+    /// filesystem-specific listing. Failure to snapshot does not block recovery: losing the
+    /// post-mortem copy is better than refusing to self-heal.
+    fn snapshot_corrupt_directory(index_oid: pg_sys::Oid, directory: &dyn Directory) {
+        let snapshot_name = format!(
+            "pg_search_corrupt_{}_{}",
+            index_oid.as_u32(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or_default()
+        );
+
+        match Self::copy_managed_files(directory, &snapshot_name) {
+            Ok(snapshot_dir) => warning!(
+                "pg_search: snapshotted corrupt index {index_oid:?} to {} before rebuilding it \
+                 empty. Run REINDEX to repopulate it.",
+                snapshot_dir.display()
+            ),
+            Err(err) => warning!(
+                "pg_search: failed to snapshot corrupt index {index_oid:?} before rebuilding it \
+                 ({err}); continuing without a post-mortem copy. Run REINDEX to repopulate it."
+            ),
+        }
+    }
+
+    fn copy_managed_files(
+        directory: &dyn Directory,
+        snapshot_name: &str,
+    ) -> Result<std::path::PathBuf> {
+        let managed_files: Vec<String> =
+            serde_json::from_slice(&directory.atomic_read(Path::new(".managed.json"))?)?;
+
+        let snapshot_dir = std::env::temp_dir().join(snapshot_name);
+        std::fs::create_dir_all(&snapshot_dir)?;
+
+        for file_name in managed_files {
+            let bytes = match directory.atomic_read(Path::new(&file_name)) {
+                Ok(bytes) => bytes,
+                Err(_) => continue, // a corrupt index is expected to be missing some of its own files
+            };
+            std::fs::write(snapshot_dir.join(&file_name), bytes)?;
+        }
+
+        Ok(snapshot_dir)
+    }
+
     pub fn perform<T, F: FnOnce(&Index) -> T>(&mut self, action: F) -> std::thread::Result<T>
     where
         F: Send + Sync,
@@ -187,14 +291,32 @@ impl SearchIndex {
 
     fn open_reader(index_relation: &PgRelation) -> Result<SearchIndexReader> {
         let directory = BlockingDirectory::new(index_relation.oid());
-        let mut index = Index::open(directory)?;
         let schema = make_schema(index_relation)?;
+        let mut index = match Index::open(directory) {
+            Ok(index) => index,
+            Err(err) if Self::is_corruption(&err) && gucs::auto_recover_corrupt_index() => {
+                let index_oid = index_relation.oid();
+                warning!(
+                    "pg_search: index {index_oid:?} failed to open ({err}), rebuilding an empty \
+                     index in its place. Run REINDEX to repopulate it."
+                );
+                Self::snapshot_corrupt_directory(index_oid, &BlockingDirectory::new(index_oid));
+                let fresh_directory = BlockingDirectory::new(index_oid);
+                let settings = IndexSettings {
+                    docstore_compress_dedicated_thread: false,
+                    ..IndexSettings::default()
+                };
+                Index::create(fresh_directory, schema.schema.clone(), settings)?
+            }
+            Err(err) => return Err(err.into()),
+        };
         SearchIndex::setup_tokenizers(&mut index, &schema);
         let reader = index
             .reader_builder()
             .reload_policy(ReloadPolicy::Manual)
             .try_into()?;
         let searcher = reader.searcher();
+        let executor = SearchIndex::executor(searcher.segment_readers().len());
 
         Ok(SearchIndexReader::new(
             index_relation,
@@ -202,12 +324,31 @@ impl SearchIndex {
             searcher,
             reader,
             schema,
+            executor,
         ))
     }
 
+    /// Reports whether `index_relation` currently opens cleanly, without mutating it. Backs
+    /// `paradedb.index_health()` so operators can check for corruption before deciding whether to
+    /// enable [`gucs::auto_recover_corrupt_index`] or run a manual `REINDEX`.
+    pub fn health_check(index_relation: &PgRelation) -> std::result::Result<(), String> {
+        let directory = BlockingDirectory::new(index_relation.oid());
+        Index::open(directory)
+            .map(|_| ())
+            .map_err(|err| err.to_string())
+    }
+
+    /// Selects the executor a reader should search with, given how many segments it has. Below
+    /// [`MIN_SEGMENTS_FOR_PARALLEL_EXECUTOR`] segments, a single-thread executor is returned
+    /// regardless of `paradedb.search_parallelism` -- collection over a single segment can't
+    /// parallelize enough to be worth a pool. Otherwise the shared, lazily-built
+    /// [`SEARCH_EXECUTOR`] pool is cloned out (cheap: its thread pool is `Arc`-backed).
     #[allow(static_mut_refs)]
-    fn executor() -> &'static Executor {
-        unsafe { &SEARCH_EXECUTOR }
+    fn executor(segment_count: usize) -> Executor {
+        if segment_count < MIN_SEGMENTS_FOR_PARALLEL_EXECUTOR {
+            return Executor::single_thread();
+        }
+        unsafe { SEARCH_EXECUTOR.clone() }
     }
 
     fn setup_tokenizers(underlying_index: &mut Index, schema: &SearchIndexSchema) {
@@ -259,11 +400,15 @@ pub enum SearchIndexError {
 
     #[error(transparent)]
     AnyhowError(#[from] anyhow::Error),
+
+    #[error("must specify key field")]
+    KeyFieldMissing,
 }
 
 fn make_schema(index_relation: &PgRelation) -> Result<SearchIndexSchema> {
     if index_relation.rd_options.is_null() {
-        panic!("must specify key field")
+        pg_sys::panic::ErrorReport::from(SearchIndexError::KeyFieldMissing)
+            .report(PgLogLevel::PG_ERROR)
     }
     let (fields, key_field_index) = unsafe { get_fields(index_relation) };
     let schema = SearchIndexSchema::new(fields, key_field_index)?;
@@ -275,6 +420,11 @@ pub fn open_search_reader(index_relation: &PgRelation) -> Result<SearchIndexRead
     SearchIndex::open_reader(index_relation)
 }
 
+/// Reports whether `index_relation` currently opens cleanly. See [`SearchIndex::health_check`].
+pub fn check_index_health(index_relation: &PgRelation) -> std::result::Result<(), String> {
+    SearchIndex::health_check(index_relation)
+}
+
 /// Open an existing index for writing
 pub fn open_search_writer(
     index_relation: &PgRelation,
@@ -3,6 +3,7 @@ use shared::logs::ParadeLogsGlobal;
 use shared::telemetry;
 
 mod api;
+mod gucs;
 
 pgrx::pg_module_magic!();
 
@@ -19,6 +20,7 @@ static PARADE_LOGS_GLOBAL: ParadeLogsGlobal = ParadeLogsGlobal::new("pg_search")
 pub unsafe extern "C" fn _PG_init() {
     telemetry::posthog::init("pg_search Deployment");
     PARADE_LOGS_GLOBAL.init();
+    gucs::init();
 }
 
 /// This module is required by `cargo pgrx test` invocations.